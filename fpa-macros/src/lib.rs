@@ -0,0 +1,165 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::{
+    parse_macro_input, Expr, ExprArray, ExprLit, Fields, ItemFn, ItemStruct, Lit, LitInt, LitStr,
+};
+
+/// Mark a handler as a transactional function.
+///
+/// `kind` is one of `"EI"`, `"EO"` or `"EQ"`. `name` defaults to the
+/// function's name, `ftr` lists the data functions referenced and `det`
+/// overrides the DETs otherwise derived from the parameters and return type.
+///
+/// ```ignore
+/// #[fpa::transaction(kind = "EI", ftr = ["Customer"])]
+/// fn create_customer(customer: NewCustomer) -> Customer { ... }
+/// ```
+#[proc_macro_attribute]
+pub fn transaction(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut kind = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("kind") {
+            kind = Some(kind_value(&meta, &["EI", "EO", "EQ"])?);
+            Ok(())
+        } else if meta.path.is_ident("name") {
+            meta.value()?.parse::<LitStr>().map(drop)
+        } else if meta.path.is_ident("ftr") {
+            let array: ExprArray = meta.value()?.parse()?;
+
+            for element in array.elems {
+                match element {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(_), ..
+                    }) => {}
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            other,
+                            "expected a data function name",
+                        ))
+                    }
+                }
+            }
+
+            Ok(())
+        } else if meta.path.is_ident("det") {
+            meta.value()?
+                .parse::<LitInt>()?
+                .base10_parse::<u32>()
+                .map(drop)
+        } else {
+            Err(meta.error("unsupported transaction property"))
+        }
+    });
+
+    parse_macro_input!(args with parser);
+
+    if kind.is_none() {
+        return missing_kind();
+    }
+
+    let item = parse_macro_input!(item as ItemFn);
+
+    quote!(#item).into()
+}
+
+/// Mark a struct as a data function whose fields are its DETs.
+///
+/// `kind` is `"ILF"` or `"EIF"`. `name` defaults to the struct's name and
+/// `ret` to one record element type. Fields marked `#[fpa(skip)]` are not
+/// counted.
+///
+/// ```ignore
+/// #[fpa::data_function(kind = "ILF")]
+/// struct Customer {
+///     id: u32,
+///     name: String,
+///     #[fpa(skip)]
+///     cache: Cache,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn data_function(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut kind = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("kind") {
+            kind = Some(kind_value(&meta, &["ILF", "EIF"])?);
+            Ok(())
+        } else if meta.path.is_ident("name") {
+            meta.value()?.parse::<LitStr>().map(drop)
+        } else if meta.path.is_ident("ret") {
+            meta.value()?
+                .parse::<LitInt>()?
+                .base10_parse::<u32>()
+                .map(drop)
+        } else {
+            Err(meta.error("unsupported data function property"))
+        }
+    });
+
+    parse_macro_input!(args with parser);
+
+    if kind.is_none() {
+        return missing_kind();
+    }
+
+    let mut item = parse_macro_input!(item as ItemStruct);
+
+    let fields = match &mut item.fields {
+        Fields::Named(fields) => fields,
+        _ => {
+            return syn::Error::new_spanned(&item, "data functions need named fields")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    for field in fields.named.iter_mut() {
+        let mut error = None;
+
+        field.attrs.retain(|attribute| {
+            if !attribute.path().is_ident("fpa") {
+                return true;
+            }
+
+            if let Err(e) = attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported field property"))
+                }
+            }) {
+                error = Some(e);
+            }
+
+            false
+        });
+
+        if let Some(error) = error {
+            return error.to_compile_error().into();
+        }
+    }
+
+    quote!(#item).into()
+}
+
+fn kind_value(meta: &ParseNestedMeta, kinds: &[&str]) -> syn::Result<String> {
+    let lit: LitStr = meta.value()?.parse()?;
+    let value = lit.value();
+
+    if kinds.contains(&value.as_str()) {
+        Ok(value)
+    } else {
+        Err(syn::Error::new(
+            lit.span(),
+            format!("expected one of {}", kinds.join(", ")),
+        ))
+    }
+}
+
+fn missing_kind() -> TokenStream {
+    syn::Error::new(Span::call_site(), "missing `kind`")
+        .to_compile_error()
+        .into()
+}
@@ -0,0 +1,6 @@
+#[fpa_macros::data_function(kind = "EI")]
+struct Customer {
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[fpa_macros::transaction(ftr = ["Customer"])]
+fn create_customer() {}
+
+fn main() {}
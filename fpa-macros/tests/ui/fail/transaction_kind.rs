@@ -0,0 +1,4 @@
+#[fpa_macros::transaction(kind = "ILF")]
+fn create_customer() {}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[fpa_macros::data_function(kind = "ILF")]
+struct Customer {
+    #[fpa(ignore)]
+    id: u32,
+}
+
+fn main() {}
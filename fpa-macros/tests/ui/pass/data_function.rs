@@ -0,0 +1,27 @@
+#[fpa_macros::data_function(kind = "ILF")]
+struct Customer {
+    id: u32,
+    name: String,
+    #[fpa(skip)]
+    cache: Option<String>,
+}
+
+#[fpa_macros::data_function(kind = "EIF", name = "Exchange rates", ret = 2)]
+struct ExchangeRate {
+    currency: String,
+}
+
+fn main() {
+    let customer = Customer {
+        id: 7,
+        name: String::new(),
+        cache: None,
+    };
+    let rate = ExchangeRate {
+        currency: String::new(),
+    };
+
+    assert_eq!(customer.id, 7);
+    assert!(customer.name.is_empty() && customer.cache.is_none());
+    assert!(rate.currency.is_empty());
+}
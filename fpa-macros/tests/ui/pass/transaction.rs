@@ -0,0 +1,20 @@
+struct Customer;
+
+#[fpa_macros::transaction(kind = "EI", ftr = ["Customer"])]
+fn create_customer(_name: String) -> Customer {
+    Customer
+}
+
+#[fpa_macros::transaction(kind = "EQ", name = "Show customer", ftr = ["Customer", "Order"])]
+fn show_customer(_id: u32) -> Customer {
+    Customer
+}
+
+#[fpa_macros::transaction(kind = "EO", det = 12)]
+fn monthly_report() {}
+
+fn main() {
+    create_customer(String::new());
+    show_customer(7);
+    monthly_report();
+}
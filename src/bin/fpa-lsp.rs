@@ -0,0 +1,8 @@
+use std::io;
+
+fn main() -> io::Result<()> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+
+    fpa_rs::LanguageServer::new().run(stdin.lock(), stdout.lock())
+}
@@ -0,0 +1,35 @@
+use std::env;
+use std::io;
+use std::net::TcpListener;
+use std::process;
+
+use fpa_rs::CountingService;
+
+const USAGE: &str = "usage: fpa-server [--listen ADDRESS] [--state FILE]";
+
+fn main() -> io::Result<()> {
+    let mut address = String::from("127.0.0.1:7878");
+    let mut state = None;
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--listen", Some(value)) => address = value,
+            ("--state", Some(value)) => state = Some(value),
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    let mut service = match state {
+        Some(path) => CountingService::open(path)?,
+        None => CountingService::new(),
+    };
+    let listener = TcpListener::bind(&address)?;
+
+    eprintln!("fpa-server: listening on http://{}", listener.local_addr()?);
+
+    service.serve(listener)
+}
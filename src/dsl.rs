@@ -0,0 +1,934 @@
+use std::fmt;
+
+use crate::project::adjustment_factors::AdjustmentFactors;
+use crate::project::basic_functional_component::{
+    BasicFunctionalComponent, ElementaryDataReferenced, FileRegistry, FunctionalClassification,
+};
+use crate::project::general_system_characteristics::{GeneralSystemCharacteristic, MAX_DEGREE};
+use crate::project::metadata::CountType;
+use crate::project::planning::Priority;
+use crate::project::progress::DeliveryStatus;
+use crate::project::value_adjustment::ValueAdjustment;
+use crate::project::weighting_factors::WeightingFactors;
+use crate::project::Project;
+
+/// Problem found while parsing a count, at a 1-based line and column.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct Diagnostic {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl Diagnostic {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Get the diagnostic's line.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Get the diagnostic's column.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Get a reference to the diagnostic's message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+#[derive(PartialEq, Debug, Clone)]
+enum Token {
+    Word(String),
+    Text(String),
+    Equals,
+    Comma,
+}
+
+/// Token with its 1-based column.
+type Spanned = (usize, Token);
+
+fn tokenize(line: &str) -> Result<Vec<Spanned>, (usize, String)> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().enumerate().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        let column = index + 1;
+
+        match c {
+            c if c.is_whitespace() => {}
+            '#' => break,
+            '=' => tokens.push((column, Token::Equals)),
+            ',' => tokens.push((column, Token::Comma)),
+            '"' => {
+                let mut text = String::new();
+
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, escaped)) => text.push(escaped),
+                            None => return Err((column, "unterminated string".to_string())),
+                        },
+                        Some((_, c)) => text.push(c),
+                        None => return Err((column, "unterminated string".to_string())),
+                    }
+                }
+
+                tokens.push((column, Token::Text(text)));
+            }
+            c => {
+                let mut word = c.to_string();
+
+                while let Some((_, c)) = chars.peek() {
+                    if c.is_whitespace() || matches!(c, '=' | ',' | '"' | '#') {
+                        break;
+                    }
+                    word.push(*c);
+                    chars.next();
+                }
+
+                tokens.push((column, Token::Word(word)));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// `key=value,value` pair of a line.
+struct Attribute {
+    column: usize,
+    key: String,
+    values: Vec<(usize, String)>,
+}
+
+fn attributes(tokens: &[Spanned], end: usize) -> Result<Vec<Attribute>, (usize, String)> {
+    let mut attributes = Vec::new();
+    let mut position = 0;
+
+    while position < tokens.len() {
+        let (column, key) = match &tokens[position] {
+            (column, Token::Word(key)) => (*column, key.clone()),
+            (column, _) => return Err((*column, "expected an attribute name".to_string())),
+        };
+
+        match tokens.get(position + 1) {
+            Some((_, Token::Equals)) => {}
+            Some((column, _)) => return Err((*column, format!("expected `=` after `{}`", key))),
+            None => return Err((end, format!("expected `=` after `{}`", key))),
+        }
+
+        position += 2;
+
+        let mut values = Vec::new();
+
+        loop {
+            match tokens.get(position) {
+                Some((column, Token::Word(value))) | Some((column, Token::Text(value))) => {
+                    values.push((*column, value.clone()));
+                }
+                Some((column, _)) => {
+                    return Err((*column, format!("expected a value for `{}`", key)))
+                }
+                None => return Err((end, format!("expected a value for `{}`", key))),
+            }
+
+            position += 1;
+
+            match tokens.get(position) {
+                Some((_, Token::Comma)) => position += 1,
+                _ => break,
+            }
+        }
+
+        attributes.push(Attribute {
+            column,
+            key,
+            values,
+        });
+    }
+
+    Ok(attributes)
+}
+
+fn number<T: std::str::FromStr>(
+    column: usize,
+    key: &str,
+    value: &str,
+) -> Result<T, (usize, String)> {
+    value.parse().map_err(|_| {
+        (
+            column,
+            format!("`{}` expects a number, found `{}`", key, value),
+        )
+    })
+}
+
+fn single(attribute: &Attribute) -> Result<&(usize, String), (usize, String)> {
+    match attribute.values.as_slice() {
+        [value] => Ok(value),
+        _ => Err((
+            attribute.values[1].0,
+            format!("`{}` takes a single value", attribute.key),
+        )),
+    }
+}
+
+fn count_by_name(attribute: &Attribute) -> bool {
+    match attribute.values.as_slice() {
+        [(_, value)] => !value.chars().all(|c| c.is_ascii_digit()),
+        _ => true,
+    }
+}
+
+/// Count given either as a number or as a list of names.
+fn count(attribute: &Attribute) -> Result<u32, (usize, String)> {
+    if count_by_name(attribute) {
+        Ok(attribute.values.len() as u32)
+    } else {
+        let (column, value) = &attribute.values[0];
+
+        number(*column, &attribute.key, value)
+    }
+}
+
+/// Build a project from a count written in the compact text format.
+///
+/// Each line is either a header directive or a component:
+///
+/// ```text
+/// @project "Billing"
+/// @type development
+/// @weights EI=3,4,6 EO=4,5,7 EQ=3,4,6 ILF=7,10,15
+/// @gsc teleprocessing=3 performance=4
+/// @vaf ifpug
+/// @cost 85.5
+///
+/// EI "Create customer" det=5 ftr=Customer,Address
+/// ILF Customer det=20 ret=2
+/// ```
+///
+/// `det`, `ftr` and `ret` take a number or a list of names to be counted.
+/// `det` and `ftr` count the input side of a transaction, while `det_out`
+/// and `ftr_out` count its output side, which drives the complexity of an
+/// EO. Components also accept `id`, `req`, `src`, `group`, `release`,
+/// `priority` (`must`, `should`, `could` or `wont`) and `status`
+/// (`not_started`, `delivered`, `accepted` or the percent complete of a
+/// component in progress). ILF weights apply to EIFs too. Every problem
+/// found is reported, not only the first.
+///
+/// The RETs of a data function are kept as a total. Assumptions, planned
+/// periods, Early & Quick levels, the counter, counting date, method
+/// version and document references of the metadata, and recorded hours and
+/// progress are not part of the format.
+pub fn project_from_dsl(source: &str) -> Result<Project, Vec<Diagnostic>> {
+    let analysis = analyse(source);
+
+    if analysis.diagnostics.is_empty() {
+        Ok(analysis.project)
+    } else {
+        Err(analysis.diagnostics)
+    }
+}
+
+/// Component declared on a line of a count.
+#[cfg_attr(not(feature = "lsp"), allow(dead_code))]
+pub(crate) struct ComponentLine {
+    pub(crate) line: usize,
+    /// Position of the component in the project.
+    pub(crate) index: usize,
+    /// Data functions listed by name in `ftr`, with their columns.
+    pub(crate) references: Vec<(usize, String)>,
+}
+
+/// Project built from the valid lines of a count, with the problems found
+/// on the others.
+pub(crate) struct Analysis {
+    pub(crate) project: Project,
+    pub(crate) diagnostics: Vec<Diagnostic>,
+    #[cfg_attr(not(feature = "lsp"), allow(dead_code))]
+    pub(crate) components: Vec<ComponentLine>,
+}
+
+pub(crate) fn analyse(source: &str) -> Analysis {
+    let mut project = Project::new();
+    let mut diagnostics = Vec::new();
+    let mut components = Vec::new();
+
+    for (index, line) in source.lines().enumerate() {
+        let number = index + 1;
+        let end = line.chars().count() + 1;
+        let result = tokenize(line).and_then(|tokens| match tokens.first() {
+            None => Ok(()),
+            Some((column, Token::Word(word))) if word.starts_with('@') => {
+                directive(&mut project, *column, word, &tokens[1..], end)
+            }
+            Some((column, Token::Word(word))) => {
+                let references = component(&mut project, *column, word, &tokens[1..], end)?;
+
+                components.push(ComponentLine {
+                    line: number,
+                    index: project.basic_functional_components().len() - 1,
+                    references,
+                });
+                Ok(())
+            }
+            Some((column, _)) => Err((*column, "expected a directive or a component".to_string())),
+        });
+
+        if let Err((column, message)) = result {
+            diagnostics.push(Diagnostic::new(number, column, message));
+        }
+    }
+
+    Analysis {
+        project,
+        diagnostics,
+        components,
+    }
+}
+
+fn directive(
+    project: &mut Project,
+    column: usize,
+    name: &str,
+    tokens: &[Spanned],
+    end: usize,
+) -> Result<(), (usize, String)> {
+    let text = |tokens: &[Spanned]| match tokens {
+        [(_, Token::Text(value))] | [(_, Token::Word(value))] => Ok(value.clone()),
+        [] => Err((end, format!("`{}` expects a value", name))),
+        [_, (column, _), ..] | [(column, _)] => {
+            Err((*column, format!("`{}` expects a single value", name)))
+        }
+    };
+
+    match name {
+        "@project" | "@purpose" | "@scope" | "@boundary" => {
+            let value = text(tokens)?;
+            let mut metadata = project.metadata().clone();
+
+            match name {
+                "@project" => metadata.set_name(value),
+                "@purpose" => metadata.set_purpose(value),
+                "@scope" => metadata.set_scope(value),
+                _ => metadata.set_boundary(value),
+            };
+            project.set_metadata(metadata);
+        }
+        "@type" => {
+            let count_type = match text(tokens)?.as_str() {
+                "development" => CountType::Development,
+                "enhancement" => CountType::Enhancement,
+                "application" => CountType::Application,
+                other => {
+                    return Err((
+                        tokens[0].0,
+                        format!(
+                        "unknown count type `{}`, expected development, enhancement or application",
+                        other
+                    ),
+                    ))
+                }
+            };
+            let mut metadata = project.metadata().clone();
+
+            metadata.set_count_type(count_type);
+            project.set_metadata(metadata);
+        }
+        "@cost" => {
+            let value = text(tokens)?;
+
+            project.set_cost_per_hour(number(tokens[0].0, "@cost", &value)?);
+        }
+        "@vaf" => {
+            let (mode, rest) = match tokens.split_first() {
+                Some(((column, Token::Word(mode)), rest)) => ((*column, mode.as_str()), rest),
+                Some(((column, _), _)) => {
+                    return Err((*column, "expected ifpug, custom or disabled".to_string()))
+                }
+                None => return Err((end, "`@vaf` expects a mode".to_string())),
+            };
+
+            let value_adjustment = match mode.1 {
+                "ifpug" | "disabled" if !rest.is_empty() => {
+                    return Err((rest[0].0, format!("`{}` takes no parameters", mode.1)))
+                }
+                "ifpug" => ValueAdjustment::Ifpug,
+                "disabled" => ValueAdjustment::Disabled,
+                "custom" => {
+                    let mut base = None;
+                    let mut step = None;
+
+                    for attribute in attributes(rest, end)? {
+                        let (column, value) = single(&attribute)?;
+                        let value = number(*column, &attribute.key, value)?;
+
+                        match attribute.key.as_str() {
+                            "base" => base = Some(value),
+                            "step" => step = Some(value),
+                            other => {
+                                return Err((
+                                    attribute.column,
+                                    format!("unknown parameter `{}`, expected base or step", other),
+                                ))
+                            }
+                        }
+                    }
+
+                    match (base, step) {
+                        (Some(base), Some(step)) => ValueAdjustment::Custom { base, step },
+                        _ => return Err((mode.0, "`custom` needs base and step".to_string())),
+                    }
+                }
+                other => {
+                    return Err((
+                        mode.0,
+                        format!(
+                            "unknown mode `{}`, expected ifpug, custom or disabled",
+                            other
+                        ),
+                    ))
+                }
+            };
+
+            project.set_value_adjustment(value_adjustment);
+        }
+        "@weights" => {
+            let mut weights = project.weighting_factors().clone();
+
+            for attribute in attributes(tokens, end)? {
+                let mut values = [0; 3];
+
+                if attribute.values.len() != 3 {
+                    return Err((
+                        attribute.column,
+                        format!(
+                            "`{}` expects simple, average and complex weights",
+                            attribute.key
+                        ),
+                    ));
+                }
+
+                for (weight, (column, value)) in values.iter_mut().zip(&attribute.values) {
+                    *weight = number(*column, &attribute.key, value)?;
+                }
+
+                match attribute.key.as_str() {
+                    "EI" => weights.set_external_input(values),
+                    "EO" => weights.set_external_output(values),
+                    "EQ" => weights.set_external_query(values),
+                    "ILF" => weights.set_referenced_logical_file(values),
+                    other => {
+                        return Err((
+                            attribute.column,
+                            format!("unknown weights `{}`, expected EI, EO, EQ or ILF", other),
+                        ))
+                    }
+                };
+            }
+
+            project.set_weighting_factors(weights);
+        }
+        "@gsc" => {
+            let mut factors: AdjustmentFactors = project.adjustment_factors().clone();
+
+            for attribute in attributes(tokens, end)? {
+                let gsc = match GeneralSystemCharacteristic::from_key(&attribute.key) {
+                    Some(gsc) => gsc,
+                    None => {
+                        return Err((
+                            attribute.column,
+                            format!("unknown characteristic `{}`", attribute.key),
+                        ))
+                    }
+                };
+                let (column, value) = single(&attribute)?;
+                let degree: u8 = number(*column, &attribute.key, value)?;
+
+                if degree > MAX_DEGREE {
+                    return Err((
+                        *column,
+                        format!("degree of influence must be at most {}", MAX_DEGREE),
+                    ));
+                }
+
+                factors.set_degree(gsc, degree);
+            }
+
+            project.set_adjustment_factors(factors);
+        }
+        other => return Err((column, format!("unknown directive `{}`", other))),
+    }
+
+    Ok(())
+}
+
+fn component(
+    project: &mut Project,
+    column: usize,
+    kind: &str,
+    tokens: &[Spanned],
+    end: usize,
+) -> Result<Vec<(usize, String)>, (usize, String)> {
+    let classification = FunctionalClassification::from_abbreviation(kind).ok_or_else(|| {
+        (
+            column,
+            format!(
+                "unknown component kind `{}`, expected EI, EO, EQ, ILF or EIF",
+                kind
+            ),
+        )
+    })?;
+
+    let (name, rest) = match tokens.split_first() {
+        Some(((_, Token::Text(name)), rest)) | Some(((_, Token::Word(name)), rest)) => {
+            (name.clone(), rest)
+        }
+        Some(((column, _), _)) => return Err((*column, "expected a component name".to_string())),
+        None => return Err((end, "expected a component name".to_string())),
+    };
+
+    let files_key = if classification.is_transactional() {
+        "ftr"
+    } else {
+        "ret"
+    };
+    let mut bfc = BasicFunctionalComponent::new(name, classification);
+    let mut det = 0;
+    let mut det_out = 0;
+    let mut files = 0;
+    let mut files_out = 0;
+    let mut seen: Vec<String> = Vec::new();
+    let mut references = Vec::new();
+
+    for attribute in attributes(rest, end)? {
+        if seen.contains(&attribute.key)
+            && !matches!(attribute.key.as_str(), "group" | "req" | "src")
+        {
+            return Err((
+                attribute.column,
+                format!("`{}` is given more than once", attribute.key),
+            ));
+        }
+
+        seen.push(attribute.key.clone());
+
+        match attribute.key.as_str() {
+            "det" => det = count(&attribute)?,
+            "det_out" => det_out = count(&attribute)?,
+            key if key == files_key => {
+                files = count(&attribute)?;
+
+                if key == "ftr" && count_by_name(&attribute) {
+                    references.extend(attribute.values);
+                }
+            }
+            "ftr_out" if files_key == "ftr" => {
+                files_out = count(&attribute)?;
+
+                if count_by_name(&attribute) {
+                    references.extend(attribute.values);
+                }
+            }
+            "ftr" | "ret" | "ftr_out" => {
+                return Err((
+                    attribute.column,
+                    format!(
+                        "`{}` does not apply to {}, use `{}`",
+                        attribute.key, kind, files_key
+                    ),
+                ))
+            }
+            "id" => {
+                bfc.set_id(single(&attribute)?.1.clone());
+            }
+            "release" => {
+                bfc.set_release(single(&attribute)?.1.clone());
+            }
+            "req" => {
+                for (_, value) in &attribute.values {
+                    bfc.add_requirement(value.clone());
+                }
+            }
+            "src" => {
+                for (_, value) in &attribute.values {
+                    bfc.add_source_reference(value.clone());
+                }
+            }
+            "priority" => {
+                let (column, value) = single(&attribute)?;
+
+                bfc.set_priority(match value.as_str() {
+                    "must" => Priority::Must,
+                    "should" => Priority::Should,
+                    "could" => Priority::Could,
+                    "wont" => Priority::Wont,
+                    other => {
+                        return Err((
+                            *column,
+                            format!(
+                                "unknown priority `{}`, expected must, should, could or wont",
+                                other
+                            ),
+                        ))
+                    }
+                });
+            }
+            "status" => {
+                let (column, value) = single(&attribute)?;
+
+                bfc.set_status(match value.as_str() {
+                    "not_started" => DeliveryStatus::NotStarted,
+                    "delivered" => DeliveryStatus::Delivered,
+                    "accepted" => DeliveryStatus::Accepted,
+                    percent => match percent.parse() {
+                        Ok(percent) if percent <= 100 => DeliveryStatus::InProgress(percent),
+                        _ => {
+                            return Err((
+                                *column,
+                                format!(
+                                    "unknown status `{}`, expected not_started, delivered, \
+                                     accepted or a percent complete",
+                                    percent
+                                ),
+                            ))
+                        }
+                    },
+                });
+            }
+            "group" => {
+                for (_, value) in &attribute.values {
+                    bfc.add_group(value.clone());
+                }
+            }
+            other => {
+                return Err((
+                    attribute.column,
+                    format!(
+                        "unknown attribute `{}`, expected det, {}, id, req, src, group, \
+                         release, priority or status",
+                        other, files_key
+                    ),
+                ))
+            }
+        }
+    }
+
+    bfc.set_edr(ElementaryDataReferenced::new(det, det_out))
+        .set_file_registry(FileRegistry::new(files, files_out));
+    project.add_bfc(bfc);
+
+    Ok(references)
+}
+
+fn quoted(value: &str) -> String {
+    let bare = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| !c.is_whitespace() && !matches!(c, '=' | ',' | '"' | '#' | '\\'))
+        && !value.starts_with('@');
+
+    if bare {
+        value.to_string()
+    } else {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+fn quoted_list(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|value| quoted(value))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Write a project in the compact text format read by `project_from_dsl`.
+pub fn project_to_dsl(project: &Project) -> String {
+    Dsl(project).to_string()
+}
+
+struct Dsl<'a>(&'a Project);
+
+impl fmt::Display for Dsl<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let project = self.0;
+        let metadata = project.metadata();
+
+        for (directive, value) in &[
+            ("@project", metadata.name()),
+            ("@purpose", metadata.purpose()),
+            ("@scope", metadata.scope()),
+            ("@boundary", metadata.boundary()),
+        ] {
+            if !value.is_empty() {
+                writeln!(f, "{} {}", directive, quoted(value))?;
+            }
+        }
+
+        let count_type = match metadata.count_type() {
+            CountType::Development => "development",
+            CountType::Enhancement => "enhancement",
+            CountType::Application => "application",
+        };
+
+        writeln!(f, "@type {}", count_type)?;
+
+        let weights = project.weighting_factors();
+
+        if weights != &WeightingFactors::default() {
+            let join = |values: &[u32; 3]| {
+                values
+                    .iter()
+                    .map(u32::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            };
+
+            writeln!(
+                f,
+                "@weights EI={} EO={} EQ={} ILF={}",
+                join(weights.external_input()),
+                join(weights.external_output()),
+                join(weights.external_query()),
+                join(weights.referenced_logical_file())
+            )?;
+        }
+
+        let ratings: Vec<String> = GeneralSystemCharacteristic::ALL
+            .iter()
+            .filter(|gsc| project.adjustment_factors().degree(**gsc) > 0)
+            .map(|gsc| {
+                format!(
+                    "{}={}",
+                    gsc.key(),
+                    project.adjustment_factors().degree(*gsc)
+                )
+            })
+            .collect();
+
+        if !ratings.is_empty() {
+            writeln!(f, "@gsc {}", ratings.join(" "))?;
+        }
+
+        match project.value_adjustment() {
+            ValueAdjustment::Ifpug => {}
+            ValueAdjustment::Custom { base, step } => {
+                writeln!(f, "@vaf custom base={} step={}", base, step)?;
+            }
+            ValueAdjustment::Disabled => {
+                writeln!(f, "@vaf disabled")?;
+            }
+        }
+
+        if project.cost_per_hour() != 0f32 {
+            writeln!(f, "@cost {}", project.cost_per_hour())?;
+        }
+
+        if !project.basic_functional_components().is_empty() {
+            writeln!(f)?;
+        }
+
+        for bfc in project.basic_functional_components() {
+            let classification = bfc.functional_classification();
+
+            let edr = bfc.elementary_data_referenced();
+            let fr = bfc.file_record();
+
+            write!(
+                f,
+                "{} {} det={}",
+                classification.abbreviation(),
+                quoted(bfc.name()),
+                edr.input()
+            )?;
+
+            if *edr.output() > 0 {
+                write!(f, " det_out={}", edr.output())?;
+            }
+
+            if classification.is_transactional() {
+                write!(f, " ftr={}", fr.input)?;
+
+                if fr.output > 0 {
+                    write!(f, " ftr_out={}", fr.output)?;
+                }
+            } else {
+                write!(f, " ret={}", fr.total())?;
+            }
+
+            if let Some(id) = bfc.id() {
+                write!(f, " id={}", quoted(id))?;
+            }
+
+            if !bfc.traceability().requirements().is_empty() {
+                write!(f, " req={}", quoted_list(bfc.traceability().requirements()))?;
+            }
+
+            if !bfc.traceability().source_references().is_empty() {
+                write!(
+                    f,
+                    " src={}",
+                    quoted_list(bfc.traceability().source_references())
+                )?;
+            }
+
+            if !bfc.groups().is_empty() {
+                write!(f, " group={}", quoted_list(bfc.groups()))?;
+            }
+
+            if let Some(release) = bfc.release() {
+                write!(f, " release={}", quoted(release))?;
+            }
+
+            if let Some(priority) = bfc.priority() {
+                let priority = match priority {
+                    Priority::Must => "must",
+                    Priority::Should => "should",
+                    Priority::Could => "could",
+                    Priority::Wont => "wont",
+                };
+
+                write!(f, " priority={}", priority)?;
+            }
+
+            match bfc.status() {
+                DeliveryStatus::NotStarted => {}
+                DeliveryStatus::InProgress(percent) => {
+                    write!(f, " status={}", percent)?;
+                }
+                DeliveryStatus::Delivered => {
+                    write!(f, " status=delivered")?;
+                }
+                DeliveryStatus::Accepted => {
+                    write!(f, " status=accepted")?;
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COUNT: &str = r#"# Billing count, first session
+@project "Billing system"
+@type enhancement
+@weights EI=3,4,6 EO=4,5,7 EQ=3,4,6 ILF=7,10,15
+@gsc teleprocessing=3 performance=4
+@vaf custom base=0.7 step=0.02
+@cost 85.5
+
+EI "Create customer" det=5 ftr=Customer,Address req=US-1
+EQ "List customers" det=name,email,phone ftr=1 group=Sales
+ILF Customer det=20 ret=2   # includes addresses
+"#;
+
+    #[test]
+    fn should_parse_count() {
+        let project = project_from_dsl(COUNT).unwrap();
+        let bfcs = project.basic_functional_components();
+
+        assert_eq!(project.metadata().name(), "Billing system");
+        assert_eq!(project.metadata().count_type(), &CountType::Enhancement);
+        assert_eq!(project.adjustment_factors().sum(), 7);
+        assert_eq!(project.cost_per_hour(), 85.5);
+        assert_eq!(project.weighting_factors().external_output(), &[4, 5, 7]);
+
+        assert_eq!(bfcs.len(), 3);
+        assert_eq!(bfcs[0].name(), "Create customer");
+        assert_eq!(bfcs[0].file_record().total(), 2);
+        assert_eq!(bfcs[0].traceability().requirements(), ["US-1"]);
+        assert_eq!(bfcs[1].elementary_data_referenced().total(), 3);
+        assert_eq!(bfcs[1].groups(), ["Sales"]);
+        assert_eq!(bfcs[2].file_record().total(), 2);
+        assert_eq!(project.total_function_point_not_adjusted(), 17f32);
+    }
+
+    #[test]
+    fn should_round_trip() {
+        let project = project_from_dsl(COUNT).unwrap();
+        let text = project_to_dsl(&project);
+        let reparsed = project_from_dsl(&text).unwrap();
+
+        assert_eq!(project_to_dsl(&reparsed), text);
+        assert_eq!(
+            reparsed.basic_functional_components(),
+            project.basic_functional_components()
+        );
+        assert!(text.contains("EI \"Create customer\" det=5 ftr=2 req=US-1\n"));
+    }
+
+    #[test]
+    fn should_round_trip_a_project_built_in_code() {
+        let mut project = Project::new();
+        let mut wf = WeightingFactors::new();
+        let mut invoice =
+            BasicFunctionalComponent::new("Invoice", FunctionalClassification::ExternalOutput);
+
+        wf.set_external_output([4, 5, 7]);
+        invoice
+            .set_edr(ElementaryDataReferenced::new(2, 4))
+            .set_file_registry(FileRegistry::new(0, 3))
+            .add_source_reference("billing.md#invoice")
+            .set_priority(Priority::Should)
+            .set_status(DeliveryStatus::InProgress(40));
+        project.set_weighting_factors(wf).add_bfc(invoice);
+
+        let text = project_to_dsl(&project);
+        let reparsed = project_from_dsl(&text).unwrap();
+
+        assert!(text.contains(
+            "EO Invoice det=2 det_out=4 ftr=0 ftr_out=3 src=\"billing.md#invoice\" \
+             priority=should status=40\n"
+        ));
+        assert_eq!(
+            reparsed.basic_functional_components(),
+            project.basic_functional_components()
+        );
+        assert_eq!(reparsed.total_function_point_not_adjusted(), 5f32);
+    }
+
+    #[test]
+    fn should_report_every_problem() {
+        let diagnostics = project_from_dsl(
+            "@gsc performance=7\nXI Foo det=1\nILF Customer det=3 ftr=1\nEI \"Open det=1\n",
+        )
+        .unwrap_err();
+        let messages: Vec<_> = diagnostics.iter().map(ToString::to_string).collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "1:18: degree of influence must be at most 5",
+                "2:1: unknown component kind `XI`, expected EI, EO, EQ, ILF or EIF",
+                "3:20: `ftr` does not apply to ILF, use `ret`",
+                "4:4: unterminated string",
+            ]
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::project::basic_functional_component::{
+    BasicFunctionalComponent, ElementaryDataReferenced, FileRegistry, FunctionalClassification,
+};
+use crate::project::Project;
+
+pub mod annotations;
+pub mod gherkin;
+pub mod graphql;
+pub mod markdown;
+#[cfg(feature = "openapi")]
+pub mod openapi;
+pub mod orm;
+pub mod proto;
+pub mod sql;
+
+/// Error raised when an importer can not read its source.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ImportError {
+    line: usize,
+    message: String,
+}
+
+impl ImportError {
+    pub fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+
+    /// Get the line, starting at 1, where the error was found.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Get a reference to the error's message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for ImportError {}
+
+/// Counter's decision on a proposal.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub enum Review {
+    #[default]
+    Pending,
+    Accepted,
+    Rejected,
+}
+
+/// How sure an importer is of a proposal.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Default)]
+pub enum Confidence {
+    /// Guessed from naming or structure only.
+    Low,
+    #[default]
+    Medium,
+    /// Backed by an explicit hint in the source.
+    High,
+}
+
+/// Component proposed by an importer, waiting for a counter's review.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct Proposal {
+    component: BasicFunctionalComponent,
+    rationale: Vec<String>,
+    confidence: Confidence,
+    review: Review,
+}
+
+impl Proposal {
+    pub fn new(component: BasicFunctionalComponent) -> Self {
+        Self {
+            component,
+            rationale: Vec::new(),
+            confidence: Confidence::default(),
+            review: Review::default(),
+        }
+    }
+
+    /// Explain why the importer proposed the component the way it did.
+    pub fn add_rationale(&mut self, rationale: impl Into<String>) -> &mut Self {
+        self.rationale.push(rationale.into());
+        self
+    }
+
+    /// Get a reference to the proposal's component.
+    pub fn component(&self) -> &BasicFunctionalComponent {
+        &self.component
+    }
+
+    /// Get a mutable reference to the proposal's component, to correct it before accepting.
+    pub fn component_mut(&mut self) -> &mut BasicFunctionalComponent {
+        &mut self.component
+    }
+
+    /// Get a reference to the proposal's rationale.
+    pub fn rationale(&self) -> &[String] {
+        &self.rationale
+    }
+
+    /// Get the proposal's confidence.
+    pub fn confidence(&self) -> Confidence {
+        self.confidence
+    }
+
+    /// Set the proposal's confidence.
+    pub fn set_confidence(&mut self, confidence: Confidence) -> &mut Self {
+        self.confidence = confidence;
+        self
+    }
+
+    /// Get the proposal's review.
+    pub fn review(&self) -> Review {
+        self.review
+    }
+
+    /// Set the proposal's review.
+    pub fn set_review(&mut self, review: Review) -> &mut Self {
+        self.review = review;
+        self
+    }
+}
+
+/// Proposals of one import, reviewed before being added to a project.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct ProposalSet {
+    proposals: Vec<Proposal>,
+}
+
+impl ProposalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, proposal: Proposal) -> &mut Self {
+        self.proposals.push(proposal);
+        self
+    }
+
+    /// Get a reference to the set's proposals.
+    pub fn proposals(&self) -> &[Proposal] {
+        &self.proposals
+    }
+
+    /// Get a mutable reference to the set's proposals.
+    pub fn proposals_mut(&mut self) -> &mut [Proposal] {
+        &mut self.proposals
+    }
+
+    /// Accept the proposal at `index`.
+    pub fn accept(&mut self, index: usize) -> &mut Self {
+        self.proposals[index].set_review(Review::Accepted);
+        self
+    }
+
+    /// Reject the proposal at `index`.
+    pub fn reject(&mut self, index: usize) -> &mut Self {
+        self.proposals[index].set_review(Review::Rejected);
+        self
+    }
+
+    /// Accept every proposal still pending.
+    pub fn accept_pending(&mut self) -> &mut Self {
+        for proposal in &mut self.proposals {
+            if proposal.review == Review::Pending {
+                proposal.review = Review::Accepted;
+            }
+        }
+
+        self
+    }
+
+    /// Proposals not reviewed yet.
+    pub fn pending(&self) -> Vec<&Proposal> {
+        self.proposals
+            .iter()
+            .filter(|proposal| proposal.review == Review::Pending)
+            .collect()
+    }
+
+    /// Project holding every proposal not rejected, for review.
+    pub fn draft(&self) -> Project {
+        let mut project = Project::new();
+
+        for proposal in &self.proposals {
+            if proposal.review != Review::Rejected {
+                project.add_bfc(proposal.component.clone());
+            }
+        }
+
+        project
+    }
+
+    /// Add the accepted components to a project.
+    pub fn apply_to(&self, project: &mut Project) {
+        for proposal in &self.proposals {
+            if proposal.review == Review::Accepted {
+                project.add_bfc(proposal.component.clone());
+            }
+        }
+    }
+}
+
+/// DETs of a component, on the output side of an EO and on the input side
+/// otherwise.
+fn edr_for(classification: &FunctionalClassification, det: u32) -> ElementaryDataReferenced {
+    match classification {
+        FunctionalClassification::ExternalOutput => ElementaryDataReferenced::new(0, det),
+        _ => ElementaryDataReferenced::new(det, 0),
+    }
+}
+
+/// FTRs or RETs of a component, on the output side of an EO and on the
+/// input side otherwise.
+fn files_for(classification: &FunctionalClassification, files: u32) -> FileRegistry {
+    match classification {
+        FunctionalClassification::ExternalOutput => FileRegistry::new(0, files),
+        _ => FileRegistry::new(files, 0),
+    }
+}
+
+/// Add a name unless it is already listed, ignoring ASCII case so that
+/// `Customer` and `customer` count once.
+fn push_unique(names: &mut Vec<String>, name: &str) {
+    if !names.iter().any(|n| n.eq_ignore_ascii_case(name)) {
+        names.push(name.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::basic_functional_component::FunctionalClassification;
+
+    #[test]
+    fn should_add_only_accepted_proposals() {
+        let mut set = ProposalSet::new();
+
+        set.add(Proposal::new(BasicFunctionalComponent::new(
+            "Customer",
+            FunctionalClassification::InternalLogicalFile,
+        )))
+        .add(Proposal::new(BasicFunctionalComponent::new(
+            "Audit",
+            FunctionalClassification::InternalLogicalFile,
+        )))
+        .add(Proposal::new(BasicFunctionalComponent::new(
+            "Order",
+            FunctionalClassification::InternalLogicalFile,
+        )));
+
+        set.reject(1).accept(0);
+
+        assert_eq!(set.pending().len(), 1);
+
+        set.accept_pending();
+
+        let mut project = Project::new();
+
+        set.apply_to(&mut project);
+
+        let names: Vec<_> = project
+            .basic_functional_components()
+            .iter()
+            .map(|bfc| bfc.name().as_str())
+            .collect();
+
+        assert_eq!(names, vec!["Customer", "Order"]);
+    }
+
+    #[test]
+    fn should_push_names_once_ignoring_case() {
+        let mut names = vec!["Customer".to_string()];
+
+        push_unique(&mut names, "customer");
+        push_unique(&mut names, "Order");
+
+        assert_eq!(names, ["Customer", "Order"]);
+    }
+}
@@ -0,0 +1,587 @@
+use std::io;
+use std::path::Path;
+
+use super::orm::{closing, skip_to_comma, tokenize, visit_rust_files};
+use super::{edr_for, files_for, Confidence, Proposal, ProposalSet};
+use crate::project::basic_functional_component::{
+    BasicFunctionalComponent, ElementaryDataReferenced, FileRegistry, FunctionalClassification,
+};
+use crate::project::Project;
+
+/// Propose the components annotated with `#[fpa::transaction]` and
+/// `#[fpa::data_function]` in the Rust sources of a crate. DETs are derived
+/// from struct fields: a data function's own, and for a transaction the
+/// fields of the structs its parameters and return type mention.
+pub fn collect_annotations(directory: impl AsRef<Path>) -> io::Result<ProposalSet> {
+    let mut scan = Scan::default();
+
+    visit_rust_files(directory.as_ref(), &mut |module, source| {
+        scan.source(module, source)
+    })?;
+
+    let mut set = ProposalSet::new();
+
+    for annotated in &scan.annotated {
+        if let Some(proposal) = scan.proposal(annotated) {
+            set.add(proposal);
+        }
+    }
+
+    Ok(set)
+}
+
+/// Project holding every annotated component of a crate. Annotations are
+/// reviewed with the code, so they are accepted as they are.
+pub fn project_from_annotations(directory: impl AsRef<Path>) -> io::Result<Project> {
+    let mut set = collect_annotations(directory)?;
+    let mut project = Project::new();
+
+    set.accept_pending().apply_to(&mut project);
+
+    Ok(project)
+}
+
+#[derive(PartialEq, Debug, Clone)]
+enum ArgumentValue {
+    Text(String),
+    Number(u32),
+    List(Vec<String>),
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct Annotation {
+    attribute: String,
+    arguments: Vec<(String, ArgumentValue)>,
+}
+
+impl Annotation {
+    fn text(&self, key: &str) -> Option<&str> {
+        self.arguments.iter().find_map(|(k, value)| match value {
+            ArgumentValue::Text(text) if k == key => Some(text.as_str()),
+            _ => None,
+        })
+    }
+
+    fn number(&self, key: &str) -> Option<u32> {
+        self.arguments.iter().find_map(|(k, value)| match value {
+            ArgumentValue::Number(number) if k == key => Some(*number),
+            _ => None,
+        })
+    }
+
+    fn list(&self, key: &str) -> Vec<String> {
+        self.arguments
+            .iter()
+            .find_map(|(k, value)| match value {
+                ArgumentValue::List(list) if k == key => Some(list.clone()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Types whose parameters are counted as DETs on their own.
+const SCALARS: [&str; 18] = [
+    "bool", "char", "str", "String", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16",
+    "i32", "i64", "i128", "isize", "f32", "f64",
+];
+
+/// Name and type tokens of a field or parameter.
+type Typed = (String, Vec<String>);
+
+#[derive(PartialEq, Debug, Clone)]
+enum Item {
+    Function {
+        name: String,
+        parameters: Vec<Typed>,
+        returns: Vec<String>,
+    },
+    Struct {
+        name: String,
+    },
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct Annotated {
+    module: String,
+    annotation: Annotation,
+    item: Item,
+}
+
+#[derive(Default)]
+struct Scan {
+    structs: Vec<(String, Vec<Typed>)>,
+    annotated: Vec<Annotated>,
+}
+
+impl Scan {
+    fn source(&mut self, module: &str, source: &str) {
+        let tokens = tokenize(source);
+        let mut pending: Option<Annotation> = None;
+        let mut i = 0;
+
+        while i < tokens.len() {
+            match tokens[i].as_str() {
+                "#" if tokens.get(i + 1).map(String::as_str) == Some("[") => {
+                    let end = match closing(&tokens, i + 1) {
+                        Some(end) => end,
+                        None => break,
+                    };
+
+                    if let Some(annotation) = annotation(&tokens[i + 2..end - 1]) {
+                        pending = Some(annotation);
+                    }
+
+                    i = end;
+                }
+                "struct" if i + 1 < tokens.len() => {
+                    let name = tokens[i + 1].clone();
+                    let (fields, end) = match struct_fields(&tokens, i + 2) {
+                        Some(fields) => fields,
+                        None => break,
+                    };
+
+                    self.structs.push((name.clone(), fields));
+
+                    if let Some(annotation) = pending.take() {
+                        self.push(module, annotation, Item::Struct { name });
+                    }
+
+                    i = end;
+                }
+                "fn" if i + 1 < tokens.len() => {
+                    let name = tokens[i + 1].clone();
+                    let (parameters, returns, end) = match signature(&tokens, i + 2) {
+                        Some(signature) => signature,
+                        None => break,
+                    };
+
+                    if let Some(annotation) = pending.take() {
+                        self.push(
+                            module,
+                            annotation,
+                            Item::Function {
+                                name,
+                                parameters,
+                                returns,
+                            },
+                        );
+                    }
+
+                    i = end;
+                }
+                ";" | "{" | "}" | "enum" | "impl" | "mod" | "trait" | "type" | "use" => {
+                    pending = None;
+                    i += 1;
+                }
+                _ => i += 1,
+            }
+        }
+    }
+
+    fn push(&mut self, module: &str, annotation: Annotation, item: Item) {
+        self.annotated.push(Annotated {
+            module: module.to_string(),
+            annotation,
+            item,
+        });
+    }
+
+    fn fields(&self, name: &str) -> Option<&[Typed]> {
+        self.structs
+            .iter()
+            .find(|(struct_name, _)| struct_name == name)
+            .map(|(_, fields)| fields.as_slice())
+    }
+
+    /// Push the DETs of a typed name: the flattened fields of the structs its
+    /// type mentions, or else the name itself.
+    fn flatten(&self, typed: &Typed, visited: &mut Vec<String>, dets: &mut Vec<String>) {
+        let structs: Vec<&String> = typed
+            .1
+            .iter()
+            .filter(|token| self.fields(token).is_some() && !visited.contains(token))
+            .collect();
+
+        if structs.is_empty() {
+            if !dets.contains(&typed.0) {
+                dets.push(typed.0.clone());
+            }
+            return;
+        }
+
+        for name in structs {
+            visited.push(name.clone());
+
+            for field in self.fields(name).unwrap_or_default() {
+                self.flatten(field, visited, dets);
+            }
+
+            visited.pop();
+        }
+    }
+
+    fn proposal(&self, annotated: &Annotated) -> Option<Proposal> {
+        let annotation = &annotated.annotation;
+        let classification = FunctionalClassification::from_abbreviation(annotation.text("kind")?)?;
+
+        let (item_name, edr, file_registry) = match (&annotated.item, annotation.attribute.as_str())
+        {
+            (
+                Item::Function {
+                    name,
+                    parameters,
+                    returns,
+                },
+                "transaction",
+            ) => {
+                let edr = match annotation.number("det") {
+                    Some(det) => edr_for(&classification, det),
+                    None => {
+                        let mut input = Vec::new();
+                        let mut output = Vec::new();
+
+                        // Parameters of other types, such as extractors of
+                        // application state, are not user data.
+                        for parameter in parameters.iter().filter(|(_, tokens)| {
+                            tokens.iter().any(|token| {
+                                self.fields(token).is_some() || SCALARS.contains(&token.as_str())
+                            })
+                        }) {
+                            self.flatten(parameter, &mut Vec::new(), &mut input);
+                        }
+
+                        self.flatten(
+                            &(String::new(), returns.clone()),
+                            &mut Vec::new(),
+                            &mut output,
+                        );
+                        output.retain(|det| !det.is_empty() && !input.contains(det));
+
+                        ElementaryDataReferenced::new(input.len() as u32, output.len() as u32)
+                    }
+                };
+
+                (
+                    name,
+                    edr,
+                    files_for(&classification, annotation.list("ftr").len() as u32),
+                )
+            }
+            (Item::Struct { name }, "data_function") => {
+                let mut dets = Vec::new();
+
+                for field in self.fields(name).unwrap_or_default() {
+                    self.flatten(field, &mut vec![name.clone()], &mut dets);
+                }
+
+                (
+                    name,
+                    ElementaryDataReferenced::new(dets.len() as u32, 0),
+                    FileRegistry::new(annotation.number("ret").unwrap_or(1), 0),
+                )
+            }
+            _ => return None,
+        };
+
+        let mut bfc = BasicFunctionalComponent::new(
+            annotation.text("name").unwrap_or(item_name),
+            classification,
+        );
+
+        bfc.set_edr(edr)
+            .set_file_registry(file_registry)
+            .add_source_reference(format!("{}::{}", annotated.module, item_name));
+
+        let mut proposal = Proposal::new(bfc);
+
+        proposal
+            .add_rationale(format!("annotated with #[fpa::{}]", annotation.attribute))
+            .set_confidence(Confidence::High);
+
+        Some(proposal)
+    }
+}
+
+/// Annotation from the tokens between `#[` and `]`, if it is one of ours.
+fn annotation(tokens: &[String]) -> Option<Annotation> {
+    let open = tokens.iter().position(|token| token == "(")?;
+    let attribute = tokens[..open].last()?.clone();
+
+    if attribute != "transaction" && attribute != "data_function" {
+        return None;
+    }
+
+    let inner = &tokens[open + 1..closing(tokens, open)? - 1];
+    let mut arguments = Vec::new();
+    let mut i = 0;
+
+    while i < inner.len() {
+        let end = skip_to_comma(inner, i);
+        let argument = &inner[i..end];
+
+        if let [key, equals, value @ ..] = argument {
+            if equals == "=" {
+                let value = match value.first().map(String::as_str) {
+                    Some("[") => ArgumentValue::List(
+                        value
+                            .iter()
+                            .filter(|token| token.starts_with('"'))
+                            .map(|token| token.trim_matches('"').to_string())
+                            .collect(),
+                    ),
+                    Some(text) if text.starts_with('"') => {
+                        ArgumentValue::Text(text.trim_matches('"').to_string())
+                    }
+                    Some(number) => ArgumentValue::Number(number.parse().ok()?),
+                    None => return None,
+                };
+
+                arguments.push((key.clone(), value));
+            }
+        }
+
+        i = end;
+    }
+
+    Some(Annotation {
+        attribute,
+        arguments,
+    })
+}
+
+/// Named fields of the struct whose generics start at `start`, with the
+/// position following the struct, or `None` if the source ends first.
+fn struct_fields(tokens: &[String], start: usize) -> Option<(Vec<Typed>, usize)> {
+    let mut i = start;
+
+    while i < tokens.len() && !matches!(tokens[i].as_str(), "{" | ";" | "(") {
+        i += 1;
+    }
+
+    if tokens.get(i).map(String::as_str) != Some("{") {
+        return Some((Vec::new(), i));
+    }
+
+    let end = closing(tokens, i)?;
+    let body = &tokens[i + 1..end - 1];
+    let mut fields = Vec::new();
+    let mut skip = false;
+    let mut j = 0;
+
+    while j < body.len() {
+        if body[j] == "#" {
+            let attribute_end = match closing(body, j + 1) {
+                Some(attribute_end) => attribute_end,
+                None => break,
+            };
+
+            skip |= body[j + 2..attribute_end - 1] == ["fpa", "(", "skip", ")"];
+            j = attribute_end;
+        } else if body.get(j + 1).map(String::as_str) == Some(":") {
+            let type_end = skip_to_comma(body, j + 2);
+
+            if !skip {
+                fields.push((
+                    body[j].clone(),
+                    body[j + 2..type_end]
+                        .iter()
+                        .filter(|t| *t != ",")
+                        .cloned()
+                        .collect(),
+                ));
+            }
+
+            skip = false;
+            j = type_end;
+        } else {
+            j += 1;
+        }
+    }
+
+    Some((fields, end))
+}
+
+/// Parameters and return type tokens of the function whose generics start
+/// at `start`, with the position following the signature, or `None` if the
+/// source ends first.
+fn signature(tokens: &[String], start: usize) -> Option<(Vec<Typed>, Vec<String>, usize)> {
+    let open = start + tokens[start..].iter().position(|token| token == "(")?;
+    let close = closing(tokens, open)?;
+    let inner = &tokens[open + 1..close - 1];
+    let mut parameters = Vec::new();
+    let mut i = 0;
+
+    while i < inner.len() {
+        let end = skip_to_comma(inner, i);
+        let parameter = &inner[i..end];
+
+        if let Some(colon) = parameter.iter().position(|token| token == ":") {
+            let name = parameter[..colon]
+                .iter()
+                .rev()
+                .find(|token| token.starts_with(|c: char| c.is_alphabetic() || c == '_'));
+
+            if let Some(name) = name.filter(|name| *name != "self") {
+                parameters.push((
+                    name.clone(),
+                    parameter[colon + 1..]
+                        .iter()
+                        .filter(|t| *t != ",")
+                        .cloned()
+                        .collect(),
+                ));
+            }
+        }
+
+        i = end;
+    }
+
+    let mut returns = Vec::new();
+    let mut end = close;
+
+    if tokens.get(close).map(String::as_str) == Some("->") {
+        end = close + 1;
+
+        while end < tokens.len() && !matches!(tokens[end].as_str(), "{" | ";" | "where") {
+            returns.push(tokens[end].clone());
+            end += 1;
+        }
+    }
+
+    Some((parameters, returns, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HANDLERS: &str = r#"
+        use fpa_rs as fpa;
+
+        #[fpa::data_function(kind = "ILF", ret = 2)]
+        #[derive(Debug)]
+        pub struct Customer {
+            pub id: u32,
+            pub name: String,
+            pub address: Address,
+            #[fpa(skip)]
+            cache: Option<Vec<u8>>,
+        }
+
+        pub struct Address {
+            street: String,
+            city: String,
+        }
+
+        pub struct NewCustomer {
+            name: String,
+            address: Address,
+        }
+
+        #[fpa::transaction(kind = "EI", ftr = ["Customer"])]
+        pub async fn create_customer(
+            State(db): State<Db>,
+            Json(customer): Json<NewCustomer>,
+        ) -> Result<Json<Customer>, Error> {
+            todo!()
+        }
+
+        impl Handlers {
+            #[get("/customers/:id")]
+            #[fpa::transaction(kind = "EQ", name = "Show customer", ftr = ["Customer", "Order"])]
+            fn show(&self, id: u32) -> Customer {
+                todo!()
+            }
+        }
+
+        #[fpa::transaction(kind = "EO", det = 12, ftr = ["Customer", "Order"])]
+        fn monthly_report() {}
+    "#;
+
+    fn collect() -> ProposalSet {
+        let directory =
+            std::env::temp_dir().join(format!("fpa-rs-annotations-{}", std::process::id()));
+
+        std::fs::create_dir_all(directory.join("src")).unwrap();
+        std::fs::write(directory.join("src/handlers.rs"), HANDLERS).unwrap();
+
+        let set = collect_annotations(&directory).unwrap();
+
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        set
+    }
+
+    #[test]
+    fn should_collect_annotated_items() {
+        let set = collect();
+        let components: Vec<_> = set.proposals().iter().map(Proposal::component).collect();
+
+        assert_eq!(components.len(), 4);
+
+        assert_eq!(components[0].name(), "Customer");
+        assert_eq!(components[0].elementary_data_referenced().total(), 4);
+        assert_eq!(components[0].file_record().total(), 2);
+        assert_eq!(
+            components[0].traceability().source_references(),
+            ["handlers::Customer"]
+        );
+
+        assert_eq!(components[1].name(), "create_customer");
+        assert_eq!(components[1].elementary_data_referenced().input(), &3);
+        assert_eq!(components[1].elementary_data_referenced().output(), &1);
+        assert_eq!(components[1].file_record().total(), 1);
+
+        assert_eq!(components[2].name(), "Show customer");
+        assert_eq!(
+            components[2].functional_classification(),
+            &FunctionalClassification::ExternalQuery
+        );
+        assert_eq!(components[2].elementary_data_referenced().input(), &1);
+        assert_eq!(components[2].elementary_data_referenced().output(), &3);
+        assert_eq!(components[2].file_record().total(), 2);
+
+        assert_eq!(components[3].elementary_data_referenced().output(), &12);
+        assert_eq!(components[3].file_record().output, 2);
+    }
+
+    #[test]
+    fn should_skip_truncated_sources() {
+        let mut scan = Scan::default();
+
+        for source in &[
+            "#[",
+            "#[fpa::transaction(kind = \"EI\")",
+            "struct Customer { #[",
+            "#[fpa::transaction(kind = \"EI\")] fn create(customer: Customer",
+        ] {
+            scan.source("truncated", source);
+        }
+
+        assert!(scan.annotated.is_empty());
+    }
+
+    #[cfg(feature = "macros")]
+    mod macros {
+        #[crate::data_function(kind = "ILF")]
+        struct Customer {
+            id: u32,
+            #[fpa(skip)]
+            cache: Option<String>,
+        }
+
+        #[crate::transaction(kind = "EQ", ftr = ["Customer"], det = 3)]
+        fn find_customer(id: u32) -> Customer {
+            Customer { id, cache: None }
+        }
+
+        #[test]
+        fn should_leave_annotated_items_untouched() {
+            let customer = find_customer(7);
+
+            assert_eq!(customer.id, 7);
+            assert_eq!(customer.cache, None);
+        }
+    }
+}
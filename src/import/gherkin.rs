@@ -0,0 +1,464 @@
+use super::{edr_for, files_for, push_unique, Confidence, ImportError, Proposal, ProposalSet};
+use crate::project::basic_functional_component::{
+    BasicFunctionalComponent, ElementaryDataReferenced, FileRegistry, FunctionalClassification,
+};
+
+const STEP_KEYWORDS: [&str; 6] = ["Given", "When", "Then", "And", "But", "*"];
+
+/// Scenario without any counting tag.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct UncountedScenario {
+    pub feature: String,
+    pub scenario: String,
+    pub line: usize,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct Transaction {
+    name: String,
+    reference: String,
+    classification: FunctionalClassification,
+    columns: Vec<String>,
+    data_functions: Vec<String>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct DataFunction {
+    name: String,
+    classification: FunctionalClassification,
+    columns: Vec<String>,
+    scenarios: usize,
+}
+
+/// Components read from Gherkin feature files, tagged `@EI`, `@EO` or `@EQ`
+/// for transactions and `@ILF:Name` or `@EIF:Name` for the data functions
+/// they reference. Tags on a feature or rule apply to all its scenarios, and
+/// DETs come from the columns of data tables and scenario outline examples.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct GherkinImport {
+    transactions: Vec<Transaction>,
+    data_functions: Vec<DataFunction>,
+    uncounted: Vec<UncountedScenario>,
+}
+
+#[derive(Default)]
+struct Scenario {
+    name: String,
+    line: usize,
+    tags: Vec<String>,
+    columns: Vec<String>,
+}
+
+impl GherkinImport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the scenarios of one feature file.
+    pub fn add_feature(&mut self, source: &str) -> Result<&mut Self, ImportError> {
+        let mut feature = String::new();
+        let mut feature_tags: Vec<String> = Vec::new();
+        let mut rule_tags: Vec<String> = Vec::new();
+        let mut pending_tags: Vec<String> = Vec::new();
+        let mut scenario: Option<Scenario> = None;
+        let mut header_expected = false;
+        let mut table_width = 0;
+        let mut lines = source.lines().enumerate();
+
+        while let Some((index, raw)) = lines.next() {
+            let number = index + 1;
+            let line = raw.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with('@') {
+                pending_tags.extend(
+                    line.split(" #")
+                        .next()
+                        .unwrap_or_default()
+                        .split_whitespace()
+                        .map(|tag| tag.trim_start_matches('@').to_string()),
+                );
+                continue;
+            }
+
+            if line.starts_with("\"\"\"") || line.starts_with("```") {
+                let delimiter = &line[..3];
+
+                loop {
+                    match lines.next() {
+                        Some((_, content)) if content.trim().starts_with(delimiter) => break,
+                        Some(_) => {}
+                        None => return Err(ImportError::new(number, "unterminated doc string")),
+                    }
+                }
+                continue;
+            }
+
+            if line.starts_with('|') {
+                if !line.ends_with('|') || line.len() < 2 {
+                    return Err(ImportError::new(number, "table row must end with `|`"));
+                }
+
+                let cells: Vec<String> = line[1..line.len() - 1]
+                    .split('|')
+                    .map(|cell| cell.trim().to_string())
+                    .collect();
+
+                if header_expected {
+                    header_expected = false;
+                    table_width = cells.len();
+
+                    if let Some(scenario) = scenario.as_mut() {
+                        for cell in cells {
+                            push_unique(&mut scenario.columns, &cell);
+                        }
+                    }
+                } else if cells.len() != table_width {
+                    return Err(ImportError::new(
+                        number,
+                        format!(
+                            "table row has {} cells, expected {}",
+                            cells.len(),
+                            table_width
+                        ),
+                    ));
+                }
+                continue;
+            }
+
+            header_expected = false;
+
+            if let Some((keyword, title)) = line.split_once(':') {
+                let title = title.trim().to_string();
+
+                match keyword.trim() {
+                    "Feature" => {
+                        self.finish(&feature, scenario.take());
+                        feature = title;
+                        feature_tags = std::mem::take(&mut pending_tags);
+                        rule_tags.clear();
+                        continue;
+                    }
+                    "Rule" => {
+                        self.finish(&feature, scenario.take());
+                        rule_tags = std::mem::take(&mut pending_tags);
+                        continue;
+                    }
+                    "Background" => {
+                        self.finish(&feature, scenario.take());
+                        pending_tags.clear();
+                        continue;
+                    }
+                    "Scenario" | "Example" | "Scenario Outline" | "Scenario Template" => {
+                        self.finish(&feature, scenario.take());
+
+                        let mut tags = feature_tags.clone();
+
+                        tags.extend(rule_tags.iter().cloned());
+                        tags.append(&mut pending_tags);
+                        scenario = Some(Scenario {
+                            name: title,
+                            line: number,
+                            tags,
+                            columns: Vec::new(),
+                        });
+                        continue;
+                    }
+                    "Examples" | "Scenarios" => {
+                        pending_tags.clear();
+                        header_expected = true;
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+
+            if line
+                .split_whitespace()
+                .next()
+                .is_some_and(|word| STEP_KEYWORDS.contains(&word))
+            {
+                header_expected = true;
+            }
+        }
+
+        self.finish(&feature, scenario.take());
+
+        Ok(self)
+    }
+
+    fn finish(&mut self, feature: &str, scenario: Option<Scenario>) {
+        let scenario = match scenario {
+            Some(scenario) => scenario,
+            None => return,
+        };
+
+        let classification = scenario.tags.iter().find_map(|tag| match tag.as_str() {
+            "EI" => Some(FunctionalClassification::ExternalInput),
+            "EO" => Some(FunctionalClassification::ExternalOutput),
+            "EQ" => Some(FunctionalClassification::ExternalQuery),
+            _ => None,
+        });
+
+        // Data function tags alone, often inherited from the feature, do not
+        // make a scenario countable.
+        let classification = match classification {
+            Some(classification) => classification,
+            None => {
+                self.uncounted.push(UncountedScenario {
+                    feature: feature.to_string(),
+                    scenario: scenario.name,
+                    line: scenario.line,
+                });
+                return;
+            }
+        };
+
+        let mut data_functions = Vec::new();
+
+        for tag in &scenario.tags {
+            let (kind, name) = match tag.split_once(':') {
+                Some(("ILF", name)) => (FunctionalClassification::InternalLogicalFile, name),
+                Some(("EIF", name)) => (FunctionalClassification::ExternalInterfaceFile, name),
+                _ => continue,
+            };
+
+            push_unique(&mut data_functions, name);
+            self.data_function(name, kind, &scenario.columns);
+        }
+
+        self.transactions.push(Transaction {
+            reference: format!("{}: {}", feature, scenario.name),
+            name: scenario.name,
+            classification,
+            columns: scenario.columns,
+            data_functions,
+        });
+    }
+
+    fn data_function(&mut self, name: &str, kind: FunctionalClassification, columns: &[String]) {
+        let position = match self.data_functions.iter().position(|df| df.name == name) {
+            Some(position) => position,
+            None => {
+                self.data_functions.push(DataFunction {
+                    name: name.to_string(),
+                    classification: kind,
+                    columns: Vec::new(),
+                    scenarios: 0,
+                });
+                self.data_functions.len() - 1
+            }
+        };
+
+        let data_function = &mut self.data_functions[position];
+
+        data_function.scenarios += 1;
+
+        for column in columns {
+            push_unique(&mut data_function.columns, column);
+        }
+    }
+
+    /// Scenarios without an EI, EO or EQ tag of their own.
+    pub fn uncounted(&self) -> &[UncountedScenario] {
+        &self.uncounted
+    }
+
+    /// Proposals for the tagged transactions, then for the data functions
+    /// they reference.
+    pub fn proposals(&self) -> ProposalSet {
+        let mut set = ProposalSet::new();
+
+        for transaction in &self.transactions {
+            let mut bfc = BasicFunctionalComponent::new(
+                &transaction.name,
+                transaction.classification.clone(),
+            );
+
+            bfc.set_edr(edr_for(
+                &transaction.classification,
+                transaction.columns.len() as u32,
+            ))
+            .set_file_registry(files_for(
+                &transaction.classification,
+                transaction.data_functions.len() as u32,
+            ))
+            .add_source_reference(&transaction.reference);
+
+            let mut proposal = Proposal::new(bfc);
+
+            proposal
+                .add_rationale(format!(
+                    "DETs from table columns: {}",
+                    transaction.columns.join(", ")
+                ))
+                .set_confidence(Confidence::High);
+            set.add(proposal);
+        }
+
+        for data_function in &self.data_functions {
+            let mut bfc = BasicFunctionalComponent::new(
+                &data_function.name,
+                data_function.classification.clone(),
+            );
+
+            bfc.set_edr(ElementaryDataReferenced::new(
+                data_function.columns.len() as u32,
+                0,
+            ))
+            .set_file_registry(FileRegistry::new(1, 0));
+
+            let mut proposal = Proposal::new(bfc);
+
+            proposal.add_rationale(format!(
+                "DETs from the tables of {} tagged scenarios",
+                data_function.scenarios
+            ));
+            set.add(proposal);
+        }
+
+        set
+    }
+}
+
+/// Import a single feature file.
+pub fn import_gherkin(source: &str) -> Result<GherkinImport, ImportError> {
+    let mut import = GherkinImport::new();
+
+    import.add_feature(source)?;
+
+    Ok(import)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CUSTOMERS: &str = r#"
+# language: en
+@ILF:Customer
+Feature: Customer management
+  Customers are the core of billing.
+
+  Background:
+    Given the following users:
+      | login | role  |
+      | ana   | admin |
+
+  @EI
+  Scenario: Register customer
+    When I register a customer with:
+      | name | email         |
+      | Ana  | ana@gmail.com |
+    Then the customer is saved
+
+  @EQ @EIF:Currency
+  Scenario Outline: Show customer balance
+    When I open customer <id>
+    Then I see "<balance>" in <currency>
+    """
+    | not | a | table |
+    """
+
+    @staging
+    Examples:
+      | id | balance | currency |
+      | 1  | 10.00   | EUR      |
+
+  Scenario: Customer list loads fast
+    Then the page loads in under a second
+"#;
+
+    #[test]
+    fn should_import_tagged_scenarios() {
+        let import = import_gherkin(CUSTOMERS).unwrap();
+        let set = import.proposals();
+        let components: Vec<_> = set.proposals().iter().map(Proposal::component).collect();
+        let names: Vec<_> = components.iter().map(|bfc| bfc.name().as_str()).collect();
+
+        assert_eq!(
+            names,
+            vec![
+                "Register customer",
+                "Show customer balance",
+                "Customer",
+                "Currency"
+            ]
+        );
+
+        assert_eq!(components[0].elementary_data_referenced().total(), 2);
+        assert_eq!(components[0].file_record().total(), 1);
+        assert_eq!(
+            components[0].traceability().source_references(),
+            ["Customer management: Register customer"]
+        );
+
+        assert_eq!(
+            components[1].functional_classification(),
+            &FunctionalClassification::ExternalQuery
+        );
+        assert_eq!(components[1].elementary_data_referenced().total(), 3);
+        assert_eq!(components[1].file_record().total(), 2);
+
+        assert_eq!(components[2].elementary_data_referenced().total(), 5);
+        assert_eq!(
+            set.proposals()[2].rationale(),
+            ["DETs from the tables of 2 tagged scenarios"]
+        );
+        assert_eq!(import.uncounted()[0].scenario, "Customer list loads fast");
+        assert_eq!(
+            components[3].functional_classification(),
+            &FunctionalClassification::ExternalInterfaceFile
+        );
+    }
+
+    #[test]
+    fn should_count_external_outputs_on_the_output_side() {
+        let import = import_gherkin(
+            "Feature: Billing\n\n  @EO @ILF:Invoice @ILF:Customer @EIF:Currency\n  \
+             Scenario: Print invoice\n    Then I see:\n      | number | total |\n",
+        )
+        .unwrap();
+        let set = import.proposals();
+        let invoice = set.proposals()[0].component();
+
+        assert_eq!(invoice.elementary_data_referenced().output(), &2);
+        assert_eq!(invoice.file_record().output, 3);
+    }
+
+    #[test]
+    fn should_report_uncounted_scenarios() {
+        let mut import = GherkinImport::new();
+
+        import
+            .add_feature("Feature: Login\n  Scenario: Sign in\n    Given a user\n")
+            .unwrap();
+
+        assert_eq!(
+            import.uncounted(),
+            [UncountedScenario {
+                feature: "Login".to_string(),
+                scenario: "Sign in".to_string(),
+                line: 2,
+            }]
+        );
+        assert!(import.proposals().proposals().is_empty());
+    }
+
+    #[test]
+    fn should_report_malformed_tables() {
+        let error = import_gherkin(
+            "Feature: A\n  @EI\n  Scenario: B\n    Given\n      | a | b |\n      | 1 |\n",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 6: table row has 1 cells, expected 2"
+        );
+    }
+}
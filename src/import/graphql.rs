@@ -0,0 +1,989 @@
+use super::{files_for, push_unique, Confidence, ImportError, Proposal, ProposalSet};
+use crate::project::basic_functional_component::{
+    BasicFunctionalComponent, ElementaryDataReferenced, FileRegistry, FunctionalClassification,
+};
+
+const BUILT_IN_SCALARS: [&str; 5] = ["Int", "Float", "String", "Boolean", "ID"];
+const ROOT_TYPES: [&str; 3] = ["Query", "Mutation", "Subscription"];
+
+/// Directive carrying counting hints.
+const HINT_DIRECTIVE: &str = "fpa";
+
+/// Propose ILF candidates for the object types of a GraphQL schema, EIs for
+/// its mutations and EQs or EOs for its queries.
+///
+/// Hints are given by the `@fpa` directive: `dataFunction`, `external` and
+/// `ignore` on types, `touches` and `derived` on root fields. The types a
+/// resolver touches can also come from a mapping file with one
+/// `Mutation.createOrder: Order, Customer` line per resolver.
+pub fn import_graphql(sdl: &str, mapping: Option<&str>) -> Result<ProposalSet, ImportError> {
+    let schema = Schema::parse(sdl)?;
+    let mapping = match mapping {
+        Some(mapping) => parse_mapping(mapping)?,
+        None => Vec::new(),
+    };
+    let mut set = ProposalSet::new();
+
+    for definition in &schema.definitions {
+        if definition.kind == DefinitionKind::Object
+            && !ROOT_TYPES.contains(&definition.name.as_str())
+            && schema.data_function(&definition.name).as_deref() == Some(definition.name.as_str())
+        {
+            set.add(schema.data_function_proposal(definition));
+        }
+    }
+
+    for (root, classification) in [
+        ("Mutation", FunctionalClassification::ExternalInput),
+        ("Query", FunctionalClassification::ExternalQuery),
+    ] {
+        for field in schema.fields(root) {
+            if field.hint("ignore").is_some_and(HintValue::is_true) {
+                continue;
+            }
+
+            let classification = match field.hint("derived") {
+                Some(derived) if derived.is_true() => FunctionalClassification::ExternalOutput,
+                _ => classification.clone(),
+            };
+            let resolver = format!("{}.{}", root, field.name);
+            let mapped = mapping
+                .iter()
+                .find(|(name, _)| *name == resolver)
+                .map(|(_, types)| types.clone());
+
+            set.add(schema.transaction_proposal(field, resolver, classification, mapped));
+        }
+    }
+
+    Ok(set)
+}
+
+fn parse_mapping(mapping: &str) -> Result<Vec<(String, Vec<String>)>, ImportError> {
+    let mut resolvers = Vec::new();
+
+    for (number, line) in mapping.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let (resolver, types) = line.split_once(':').ok_or_else(|| {
+            ImportError::new(number + 1, "expected `Type.field: DataFunction, ...`")
+        })?;
+
+        resolvers.push((
+            resolver.trim().to_string(),
+            types
+                .split(',')
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(str::to_string)
+                .collect(),
+        ));
+    }
+
+    Ok(resolvers)
+}
+
+#[derive(PartialEq, Debug, Clone)]
+enum HintValue {
+    Text(String),
+    Boolean(bool),
+    List(Vec<HintValue>),
+    Other,
+}
+
+impl HintValue {
+    fn is_true(&self) -> bool {
+        *self == HintValue::Boolean(true)
+    }
+
+    fn texts(&self) -> Vec<String> {
+        match self {
+            HintValue::Text(text) => vec![text.clone()],
+            HintValue::List(values) => values.iter().flat_map(HintValue::texts).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct Directive {
+    name: String,
+    arguments: Vec<(String, HintValue)>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct TypeReference {
+    name: String,
+    list: bool,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct Field {
+    name: String,
+    arguments: Vec<Field>,
+    type_reference: TypeReference,
+    directives: Vec<Directive>,
+}
+
+fn hint<'a>(directives: &'a [Directive], argument: &str) -> Option<&'a HintValue> {
+    directives
+        .iter()
+        .filter(|directive| directive.name == HINT_DIRECTIVE)
+        .flat_map(|directive| &directive.arguments)
+        .find(|(name, _)| name == argument)
+        .map(|(_, value)| value)
+}
+
+impl Field {
+    fn hint(&self, argument: &str) -> Option<&HintValue> {
+        hint(&self.directives, argument)
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum DefinitionKind {
+    Object,
+    Interface,
+    Input,
+    Enum,
+    Scalar,
+    Union,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct Definition {
+    kind: DefinitionKind,
+    name: String,
+    fields: Vec<Field>,
+    directives: Vec<Directive>,
+}
+
+impl Definition {
+    fn hint(&self, argument: &str) -> Option<&HintValue> {
+        hint(&self.directives, argument)
+    }
+}
+
+struct Schema {
+    definitions: Vec<Definition>,
+}
+
+impl Schema {
+    fn parse(sdl: &str) -> Result<Self, ImportError> {
+        let mut parser = Parser {
+            tokens: tokenize(sdl)?,
+            position: 0,
+        };
+        let mut definitions: Vec<Definition> = Vec::new();
+
+        while parser.peek().is_some() {
+            if let Some(definition) = parser.definition()? {
+                // `extend type X` adds to X rather than defining a new type.
+                match definitions.iter_mut().find(|d| d.name == definition.name) {
+                    Some(existing) => {
+                        existing.fields.extend(definition.fields);
+                        existing.directives.extend(definition.directives);
+                    }
+                    None => definitions.push(definition),
+                }
+            }
+        }
+
+        Ok(Self { definitions })
+    }
+
+    /// Definition with a name, its extensions merged in.
+    fn definition(&self, name: &str) -> Option<&Definition> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.name == name)
+    }
+
+    fn fields(&self, name: &str) -> &[Field] {
+        self.definition(name)
+            .map(|definition| definition.fields.as_slice())
+            .unwrap_or_default()
+    }
+
+    fn kind_of(&self, name: &str) -> Option<DefinitionKind> {
+        if BUILT_IN_SCALARS.contains(&name) {
+            return Some(DefinitionKind::Scalar);
+        }
+
+        self.definition(name).map(|definition| definition.kind)
+    }
+
+    fn is_field_value(&self, name: &str) -> bool {
+        matches!(
+            self.kind_of(name),
+            Some(DefinitionKind::Scalar) | Some(DefinitionKind::Enum) | None
+        )
+    }
+
+    /// Data function an object type belongs to, unless ignored.
+    fn data_function(&self, name: &str) -> Option<String> {
+        let definition = self
+            .definition(name)
+            .filter(|definition| definition.kind == DefinitionKind::Object)?;
+
+        if definition.hint("ignore").is_some_and(HintValue::is_true) {
+            return None;
+        }
+
+        Some(
+            definition
+                .hint("dataFunction")
+                .and_then(|value| value.texts().into_iter().next())
+                .unwrap_or_else(|| name.to_string()),
+        )
+    }
+
+    /// Fields of a type counted as DETs: values and single references to
+    /// other types, leaving out lists of other types.
+    fn data_element_types(&self, name: &str, dets: &mut Vec<String>) {
+        for field in self.fields(name) {
+            if self.is_field_value(&field.type_reference.name) || !field.type_reference.list {
+                push_unique(dets, &field.name);
+            }
+        }
+    }
+
+    /// Leaf fields of an input, following nested input types.
+    fn input_fields(&self, field: &Field, visited: &mut Vec<String>, dets: &mut Vec<String>) {
+        let type_name = &field.type_reference.name;
+
+        if self.kind_of(type_name) != Some(DefinitionKind::Input) {
+            push_unique(dets, &field.name);
+            return;
+        }
+
+        if visited.contains(type_name) {
+            return;
+        }
+
+        visited.push(type_name.clone());
+
+        for nested in self.fields(type_name) {
+            self.input_fields(nested, visited, dets);
+        }
+    }
+
+    fn data_function_proposal(&self, definition: &Definition) -> Proposal {
+        let members: Vec<&Definition> = self
+            .definitions
+            .iter()
+            .filter(|d| d.kind == DefinitionKind::Object)
+            .filter(|d| self.data_function(&d.name).as_deref() == Some(definition.name.as_str()))
+            .collect();
+        let mut dets = Vec::new();
+
+        for member in &members {
+            self.data_element_types(&member.name, &mut dets);
+        }
+
+        let external = definition.hint("external").is_some_and(HintValue::is_true);
+        let classification = if external {
+            FunctionalClassification::ExternalInterfaceFile
+        } else {
+            FunctionalClassification::InternalLogicalFile
+        };
+        let mut bfc = BasicFunctionalComponent::new(&definition.name, classification);
+
+        bfc.set_edr(ElementaryDataReferenced::new(dets.len() as u32, 0))
+            .set_file_registry(FileRegistry::new(members.len() as u32, 0));
+
+        for member in &members {
+            bfc.add_source_reference(format!("type {}", member.name));
+        }
+
+        let mut proposal = Proposal::new(bfc);
+
+        for member in &members[1..] {
+            proposal.add_rationale(format!(
+                "{} grouped into {} as a RET",
+                member.name, definition.name
+            ));
+        }
+
+        if definition.hint("dataFunction").is_some() || external {
+            proposal.set_confidence(Confidence::High);
+        } else {
+            proposal.add_rationale("object type without hints, candidate only");
+        }
+
+        proposal
+    }
+
+    fn transaction_proposal(
+        &self,
+        field: &Field,
+        resolver: String,
+        classification: FunctionalClassification,
+        mapped: Option<Vec<String>>,
+    ) -> Proposal {
+        let mut input = Vec::new();
+        let mut visited = Vec::new();
+
+        for argument in &field.arguments {
+            self.input_fields(argument, &mut visited, &mut input);
+        }
+
+        let mut output = Vec::new();
+
+        self.data_element_types(&field.type_reference.name, &mut output);
+        output.retain(|det| !input.iter().any(|i| i.eq_ignore_ascii_case(det)));
+
+        let (touches, confidence, rationale) = match (field.hint("touches"), mapped) {
+            (Some(touches), _) => (touches.texts(), Confidence::High, "FTRs from @fpa(touches)"),
+            (None, Some(mapped)) => (mapped, Confidence::High, "FTRs from the mapping file"),
+            (None, None) => (
+                self.data_function(&field.type_reference.name)
+                    .into_iter()
+                    .collect(),
+                Confidence::Low,
+                "FTRs inferred from the returned type",
+            ),
+        };
+        let mut file_types_referenced = Vec::new();
+
+        for touched in &touches {
+            push_unique(&mut file_types_referenced, touched);
+        }
+
+        let file_registry = files_for(&classification, file_types_referenced.len() as u32);
+        let mut bfc = BasicFunctionalComponent::new(&field.name, classification);
+
+        bfc.set_edr(ElementaryDataReferenced::new(
+            input.len() as u32,
+            output.len() as u32,
+        ))
+        .set_file_registry(file_registry)
+        .add_source_reference(resolver);
+
+        let mut proposal = Proposal::new(bfc);
+
+        proposal
+            .add_rationale(format!(
+                "{} DETs from input fields, {} from selection fields",
+                input.len(),
+                output.len()
+            ))
+            .add_rationale(format!(
+                "{}: {}",
+                rationale,
+                file_types_referenced.join(", ")
+            ))
+            .set_confidence(confidence);
+
+        proposal
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+enum TokenKind {
+    Name(String),
+    Text(String),
+    Number,
+    Punctuator(char),
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    line: usize,
+}
+
+fn tokenize(sdl: &str) -> Result<Vec<Token>, ImportError> {
+    let chars: Vec<char> = sdl.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = line;
+
+        if c == '\n' {
+            line += 1;
+            i += 1;
+        } else if c.is_whitespace() || c == ',' || c == '\u{feff}' {
+            i += 1;
+        } else if c == '#' {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '"' {
+            let block = chars.get(i + 1) == Some(&'"') && chars.get(i + 2) == Some(&'"');
+            let mut text = String::new();
+
+            i += if block { 3 } else { 1 };
+
+            loop {
+                match chars.get(i) {
+                    None => return Err(ImportError::new(start, "unterminated string")),
+                    Some('"') if !block => break,
+                    Some('"')
+                        if chars.get(i + 1) == Some(&'"') && chars.get(i + 2) == Some(&'"') =>
+                    {
+                        i += 2;
+                        break;
+                    }
+                    Some('\\') => {
+                        i += 1;
+                        if let Some(&escaped) = chars.get(i) {
+                            text.push(escaped);
+                        }
+                    }
+                    Some('\n') if !block => {
+                        return Err(ImportError::new(start, "unterminated string"))
+                    }
+                    Some(&other) => {
+                        if other == '\n' {
+                            line += 1;
+                        }
+                        text.push(other);
+                    }
+                }
+                i += 1;
+            }
+
+            i += 1;
+            tokens.push(Token {
+                kind: TokenKind::Text(text),
+                line: start,
+            });
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let mut name = String::new();
+
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                name.push(chars[i]);
+                i += 1;
+            }
+
+            tokens.push(Token {
+                kind: TokenKind::Name(name),
+                line: start,
+            });
+        } else if c.is_ascii_digit() || c == '-' {
+            i += 1;
+
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                i += 1;
+            }
+
+            tokens.push(Token {
+                kind: TokenKind::Number,
+                line: start,
+            });
+        } else if c == '.' && chars.get(i + 1) == Some(&'.') && chars.get(i + 2) == Some(&'.') {
+            i += 3;
+            tokens.push(Token {
+                kind: TokenKind::Punctuator('.'),
+                line: start,
+            });
+        } else if "!$&()[]{}:=@|".contains(c) {
+            i += 1;
+            tokens.push(Token {
+                kind: TokenKind::Punctuator(c),
+                line: start,
+            });
+        } else {
+            return Err(ImportError::new(
+                start,
+                format!("unexpected character {:?}", c),
+            ));
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn line(&self) -> usize {
+        self.peek()
+            .or_else(|| self.tokens.last())
+            .map_or(1, |token| token.line)
+    }
+
+    fn error(&self, message: impl Into<String>) -> ImportError {
+        ImportError::new(self.line(), message)
+    }
+
+    fn peek_punctuator(&self, punctuator: char) -> bool {
+        self.peek()
+            .is_some_and(|token| token.kind == TokenKind::Punctuator(punctuator))
+    }
+
+    fn eat_punctuator(&mut self, punctuator: char) -> bool {
+        let found = self.peek_punctuator(punctuator);
+
+        if found {
+            self.position += 1;
+        }
+
+        found
+    }
+
+    fn expect_punctuator(&mut self, punctuator: char) -> Result<(), ImportError> {
+        if self.eat_punctuator(punctuator) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{}`", punctuator)))
+        }
+    }
+
+    fn peek_name(&self) -> Option<&str> {
+        match self.peek().map(|token| &token.kind) {
+            Some(TokenKind::Name(name)) => Some(name),
+            _ => None,
+        }
+    }
+
+    fn eat_name(&mut self, expected: &str) -> bool {
+        let found = self.peek_name() == Some(expected);
+
+        if found {
+            self.position += 1;
+        }
+
+        found
+    }
+
+    fn name(&mut self) -> Result<String, ImportError> {
+        match self.peek_name() {
+            Some(name) => {
+                let name = name.to_string();
+                self.position += 1;
+                Ok(name)
+            }
+            None => Err(self.error("expected a name")),
+        }
+    }
+
+    fn skip_description(&mut self) {
+        if let Some(TokenKind::Text(_)) = self.peek().map(|token| &token.kind) {
+            self.position += 1;
+        }
+    }
+
+    fn definition(&mut self) -> Result<Option<Definition>, ImportError> {
+        self.skip_description();
+        self.eat_name("extend");
+
+        let keyword = self.name()?;
+        let kind = match keyword.as_str() {
+            "type" => DefinitionKind::Object,
+            "interface" => DefinitionKind::Interface,
+            "input" => DefinitionKind::Input,
+            "enum" => DefinitionKind::Enum,
+            "scalar" => DefinitionKind::Scalar,
+            "union" => DefinitionKind::Union,
+            "schema" => {
+                self.directives()?;
+                self.skip_block()?;
+                return Ok(None);
+            }
+            "directive" => {
+                self.expect_punctuator('@')?;
+                self.name()?;
+                if self.peek_punctuator('(') {
+                    self.arguments_definition()?;
+                }
+                self.eat_name("repeatable");
+                if !self.eat_name("on") {
+                    return Err(self.error("expected `on`"));
+                }
+                self.eat_punctuator('|');
+                self.name()?;
+                while self.eat_punctuator('|') {
+                    self.name()?;
+                }
+                return Ok(None);
+            }
+            other => return Err(self.error(format!("unexpected `{}`", other))),
+        };
+        let name = self.name()?;
+
+        if self.eat_name("implements") {
+            self.eat_punctuator('&');
+            self.name()?;
+            while self.eat_punctuator('&') {
+                self.name()?;
+            }
+        }
+
+        let directives = self.directives()?;
+        let mut fields = Vec::new();
+
+        match kind {
+            DefinitionKind::Object | DefinitionKind::Interface | DefinitionKind::Input => {
+                if self.eat_punctuator('{') {
+                    while !self.eat_punctuator('}') {
+                        fields.push(self.field(kind == DefinitionKind::Input)?);
+                    }
+                }
+            }
+            DefinitionKind::Enum => self.skip_block()?,
+            DefinitionKind::Union => {
+                if self.eat_punctuator('=') {
+                    self.eat_punctuator('|');
+                    self.name()?;
+                    while self.eat_punctuator('|') {
+                        self.name()?;
+                    }
+                }
+            }
+            DefinitionKind::Scalar => {}
+        }
+
+        Ok(Some(Definition {
+            kind,
+            name,
+            fields,
+            directives,
+        }))
+    }
+
+    fn skip_block(&mut self) -> Result<(), ImportError> {
+        if !self.eat_punctuator('{') {
+            return Ok(());
+        }
+
+        let mut depth = 1;
+
+        while depth > 0 {
+            match self.peek().map(|token| &token.kind) {
+                None => return Err(self.error("unclosed `{`")),
+                Some(TokenKind::Punctuator('{')) => depth += 1,
+                Some(TokenKind::Punctuator('}')) => depth -= 1,
+                _ => {}
+            }
+            self.position += 1;
+        }
+
+        Ok(())
+    }
+
+    fn field(&mut self, input: bool) -> Result<Field, ImportError> {
+        if self.peek().is_none() {
+            return Err(self.error("unclosed `{`"));
+        }
+
+        self.skip_description();
+
+        let name = self.name()?;
+        let arguments = if !input && self.peek_punctuator('(') {
+            self.arguments_definition()?
+        } else {
+            Vec::new()
+        };
+
+        self.expect_punctuator(':')?;
+
+        let type_reference = self.type_reference()?;
+
+        if self.eat_punctuator('=') {
+            self.value()?;
+        }
+
+        Ok(Field {
+            name,
+            arguments,
+            type_reference,
+            directives: self.directives()?,
+        })
+    }
+
+    fn arguments_definition(&mut self) -> Result<Vec<Field>, ImportError> {
+        let mut arguments = Vec::new();
+
+        self.expect_punctuator('(')?;
+
+        while !self.eat_punctuator(')') {
+            if self.peek().is_none() {
+                return Err(self.error("unclosed `(`"));
+            }
+
+            arguments.push(self.field(true)?);
+        }
+
+        Ok(arguments)
+    }
+
+    fn type_reference(&mut self) -> Result<TypeReference, ImportError> {
+        let reference = if self.eat_punctuator('[') {
+            let inner = self.type_reference()?;
+            self.expect_punctuator(']')?;
+            TypeReference {
+                name: inner.name,
+                list: true,
+            }
+        } else {
+            TypeReference {
+                name: self.name()?,
+                list: false,
+            }
+        };
+
+        self.eat_punctuator('!');
+
+        Ok(reference)
+    }
+
+    fn directives(&mut self) -> Result<Vec<Directive>, ImportError> {
+        let mut directives = Vec::new();
+
+        while self.eat_punctuator('@') {
+            let name = self.name()?;
+            let mut arguments = Vec::new();
+
+            if self.eat_punctuator('(') {
+                while !self.eat_punctuator(')') {
+                    let argument = self.name()?;
+                    self.expect_punctuator(':')?;
+                    arguments.push((argument, self.value()?));
+                }
+            }
+
+            directives.push(Directive { name, arguments });
+        }
+
+        Ok(directives)
+    }
+
+    fn value(&mut self) -> Result<HintValue, ImportError> {
+        let token = self
+            .peek()
+            .cloned()
+            .ok_or_else(|| self.error("expected a value"))?;
+
+        self.position += 1;
+
+        Ok(match token.kind {
+            TokenKind::Text(text) => HintValue::Text(text),
+            TokenKind::Name(name) if name == "true" => HintValue::Boolean(true),
+            TokenKind::Name(name) if name == "false" => HintValue::Boolean(false),
+            TokenKind::Name(_) | TokenKind::Number => HintValue::Other,
+            TokenKind::Punctuator('$') => {
+                self.name()?;
+                HintValue::Other
+            }
+            TokenKind::Punctuator('[') => {
+                let mut values = Vec::new();
+
+                while !self.eat_punctuator(']') {
+                    values.push(self.value()?);
+                }
+
+                HintValue::List(values)
+            }
+            TokenKind::Punctuator('{') => {
+                while !self.eat_punctuator('}') {
+                    self.name()?;
+                    self.expect_punctuator(':')?;
+                    self.value()?;
+                }
+
+                HintValue::Other
+            }
+            TokenKind::Punctuator(other) => {
+                return Err(ImportError::new(
+                    token.line,
+                    format!("unexpected `{}`", other),
+                ))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SDL: &str = r#"
+        "Directive carrying function point hints."
+        directive @fpa(
+            dataFunction: String
+            touches: [String!]
+            derived: Boolean
+            external: Boolean
+            ignore: Boolean
+        ) repeatable on OBJECT | FIELD_DEFINITION
+
+        scalar DateTime
+
+        enum Status { ACTIVE, BLOCKED }
+
+        type Customer implements Node & Audited {
+            id: ID!
+            name: String!
+            status: Status
+            createdAt: DateTime
+            address: Address
+            orders: [Order!]!
+        }
+
+        type Address @fpa(dataFunction: "Customer") {
+            street: String
+            city: String
+        }
+
+        type Order {
+            id: ID!
+            customer: Customer!
+            total: Float
+        }
+
+        type Currency @fpa(external: true) {
+            code: String!
+            rate: Float
+        }
+
+        type PageInfo @fpa(ignore: true) {
+            hasNextPage: Boolean!
+        }
+
+        input NewCustomer {
+            name: String!
+            address: NewAddress
+        }
+
+        input NewAddress {
+            street: String
+            city: String = "Lisbon"
+        }
+
+        type Query {
+            customer(id: ID!): Customer
+            """
+            Monthly revenue, computed from orders.
+            """
+            revenue(month: Int!): Float @fpa(derived: true, touches: ["Order", "Currency"])
+        }
+
+        extend type Mutation {
+            createCustomer(input: NewCustomer!): Customer
+            health: Boolean @fpa(ignore: true)
+        }
+    "#;
+
+    #[test]
+    fn should_propose_data_functions() {
+        let set = import_graphql(SDL, None).unwrap();
+        let names: Vec<_> = set
+            .proposals()
+            .iter()
+            .map(|proposal| proposal.component().name().as_str())
+            .collect();
+
+        assert_eq!(
+            names,
+            vec![
+                "Customer",
+                "Order",
+                "Currency",
+                "createCustomer",
+                "customer",
+                "revenue"
+            ]
+        );
+
+        let customer = &set.proposals()[0];
+
+        assert_eq!(customer.component().elementary_data_referenced().total(), 7);
+        assert_eq!(customer.component().file_record().total(), 2);
+        assert_eq!(customer.confidence(), Confidence::Medium);
+        assert_eq!(
+            set.proposals()[2].component().functional_classification(),
+            &FunctionalClassification::ExternalInterfaceFile
+        );
+        assert_eq!(set.proposals()[2].confidence(), Confidence::High);
+    }
+
+    #[test]
+    fn should_propose_transactions() {
+        let mapping = "# resolvers\nQuery.customer: Customer, Order\n";
+        let set = import_graphql(SDL, Some(mapping)).unwrap();
+
+        let create = &set.proposals()[3];
+
+        assert_eq!(
+            create.component().functional_classification(),
+            &FunctionalClassification::ExternalInput
+        );
+        assert_eq!(create.component().elementary_data_referenced().input(), &3);
+        assert_eq!(create.component().elementary_data_referenced().output(), &4);
+        assert_eq!(create.component().file_record().total(), 1);
+        assert_eq!(create.confidence(), Confidence::Low);
+
+        let customer = &set.proposals()[4];
+
+        assert_eq!(customer.component().file_record().total(), 2);
+        assert_eq!(customer.confidence(), Confidence::High);
+        assert_eq!(
+            customer.rationale()[1],
+            "FTRs from the mapping file: Customer, Order"
+        );
+
+        let revenue = &set.proposals()[5];
+
+        assert_eq!(
+            revenue.component().functional_classification(),
+            &FunctionalClassification::ExternalOutput
+        );
+        assert_eq!(revenue.component().file_record().output, 2);
+        assert_eq!(
+            revenue.component().traceability().source_references(),
+            ["Query.revenue"]
+        );
+    }
+
+    #[test]
+    fn should_merge_type_extensions() {
+        let sdl = r#"
+            type Customer {
+                id: ID!
+                name: String!
+            }
+
+            extend type Customer @fpa(dataFunction: "Customer") {
+                email: String
+            }
+        "#;
+        let set = import_graphql(sdl, None).unwrap();
+
+        assert_eq!(set.proposals().len(), 1);
+
+        let customer = set.proposals()[0].component();
+
+        assert_eq!(customer.elementary_data_referenced().total(), 3);
+        assert_eq!(customer.file_record().total(), 1);
+        assert_eq!(set.proposals()[0].confidence(), Confidence::High);
+    }
+
+    #[test]
+    fn should_report_syntax_errors() {
+        let error = import_graphql("type A {\n  id: ID!\n  name String\n}", None).unwrap_err();
+
+        assert_eq!(error.to_string(), "line 3: expected `:`");
+        assert_eq!(
+            import_graphql("", Some("Query.a Customer"))
+                .unwrap_err()
+                .line(),
+            1
+        );
+    }
+}
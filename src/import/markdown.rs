@@ -0,0 +1,324 @@
+use std::fmt::Write;
+
+use super::{edr_for, files_for, ImportError};
+use crate::project::basic_functional_component::{
+    BasicFunctionalComponent, FunctionalClassification,
+};
+use crate::project::Project;
+
+const APPENDIX_START: &str = "<!-- fpa:appendix -->";
+const APPENDIX_END: &str = "<!-- /fpa:appendix -->";
+
+/// Build a project from a Markdown document whose headings and list items
+/// end with a tag such as `{EI det=5 ftr=2}` or `{ILF det=12 ret=3}`.
+///
+/// The first level one heading names the project. Fenced code blocks and a
+/// previously generated appendix are skipped, and trailing braces not
+/// starting with a component kind, such as `{#setup}`, are left as text.
+pub fn import_markdown(source: &str) -> Result<Project, ImportError> {
+    let mut project = Project::new();
+    let mut named = false;
+    let mut fence: Option<&str> = None;
+    let mut in_appendix = false;
+
+    for (index, raw) in source.lines().enumerate() {
+        let number = index + 1;
+        let line = raw.trim();
+
+        if let Some(delimiter) = fence {
+            if line.starts_with(delimiter) {
+                fence = None;
+            }
+            continue;
+        }
+
+        if line.starts_with("```") || line.starts_with("~~~") {
+            fence = Some(&line[..3]);
+            continue;
+        }
+
+        if line == APPENDIX_START {
+            in_appendix = true;
+            continue;
+        }
+
+        if line == APPENDIX_END {
+            in_appendix = false;
+            continue;
+        }
+
+        if in_appendix {
+            continue;
+        }
+
+        let (heading, text) = match item_text(line) {
+            Some(item) => item,
+            None => continue,
+        };
+
+        let tag = text
+            .rfind('{')
+            .filter(|_| text.ends_with('}'))
+            .and_then(|start| {
+                let mut words = text[start + 1..text.len() - 1].split_whitespace();
+                let classification = FunctionalClassification::from_abbreviation(words.next()?)?;
+
+                Some((start, classification, words))
+            });
+        let (tag_start, classification, words) = match tag {
+            Some(tag) => tag,
+            None => {
+                if heading == 1 && !named {
+                    let mut metadata = project.metadata().clone();
+
+                    metadata.set_name(text);
+                    project.set_metadata(metadata);
+                    named = true;
+                }
+                continue;
+            }
+        };
+
+        let name = text[..tag_start].trim();
+
+        if name.is_empty() {
+            return Err(ImportError::new(number, "tagged item has no name"));
+        }
+
+        let bfc = parse_tag(name, classification, words)
+            .map_err(|message| ImportError::new(number, message))?;
+
+        project.add_bfc(bfc);
+    }
+
+    if fence.is_some() {
+        return Err(ImportError::new(
+            source.lines().count(),
+            "unterminated code block",
+        ));
+    }
+
+    Ok(project)
+}
+
+/// Heading level (zero for list items) and text of a line.
+fn item_text(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+
+    if (1..=6).contains(&level) && line[level..].starts_with(' ') {
+        return Some((level, line[level..].trim()));
+    }
+
+    for bullet in &["- ", "* ", "+ "] {
+        if let Some(text) = line.strip_prefix(bullet) {
+            return Some((0, text.trim()));
+        }
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+
+    if digits > 0 {
+        if let Some(text) = line[digits..].strip_prefix(". ") {
+            return Some((0, text.trim()));
+        }
+    }
+
+    None
+}
+
+fn parse_tag<'a>(
+    name: &str,
+    classification: FunctionalClassification,
+    words: impl Iterator<Item = &'a str>,
+) -> Result<BasicFunctionalComponent, String> {
+    let files_key = if classification.is_transactional() {
+        "ftr"
+    } else {
+        "ret"
+    };
+    let mut det = 0;
+    let mut files = 0;
+
+    for word in words {
+        let (key, value) = word
+            .split_once('=')
+            .ok_or_else(|| format!("expected `key=value`, found `{}`", word))?;
+        let value: u32 = value
+            .parse()
+            .map_err(|_| format!("`{}` must be a whole number", key))?;
+
+        match key {
+            "det" => det = value,
+            key if key == files_key => files = value,
+            "ftr" | "ret" => {
+                return Err(format!(
+                    "`{}` does not apply to {}",
+                    key,
+                    classification.abbreviation()
+                ))
+            }
+            other => return Err(format!("unknown property `{}`", other)),
+        }
+    }
+
+    let edr = edr_for(&classification, det);
+    let file_registry = files_for(&classification, files);
+    let mut bfc = BasicFunctionalComponent::new(name, classification);
+
+    bfc.set_edr(edr).set_file_registry(file_registry);
+
+    Ok(bfc)
+}
+
+/// Write a table with the complexity and weight of every component of
+/// `project` at the end of `source`, replacing a previously generated one.
+pub fn write_markdown_appendix(source: &str, project: &Project) -> String {
+    let mut output = String::new();
+    let mut in_appendix = false;
+
+    for line in source.lines() {
+        match line.trim() {
+            APPENDIX_START => in_appendix = true,
+            APPENDIX_END => in_appendix = false,
+            _ if !in_appendix => {
+                output.push_str(line);
+                output.push('\n');
+            }
+            _ => {}
+        }
+    }
+
+    while output.ends_with("\n\n") {
+        output.pop();
+    }
+
+    if !output.is_empty() {
+        output.push('\n');
+    }
+
+    let weighting_factors = project.weighting_factors();
+
+    // Writing to a String never fails.
+    let _ = writeln!(output, "{}", APPENDIX_START);
+    let _ = writeln!(output, "## Function point count\n");
+    let _ = writeln!(
+        output,
+        "| Type | Component | DET | FTR/RET | Complexity | Weight |"
+    );
+    let _ = writeln!(output, "| --- | --- | ---: | ---: | --- | ---: |");
+
+    for bfc in project.basic_functional_components() {
+        let _ = writeln!(
+            output,
+            "| {} | {} | {} | {} | {} | {} |",
+            bfc.functional_classification().abbreviation(),
+            bfc.name().replace('|', "\\|"),
+            bfc.elementary_data_referenced().total(),
+            bfc.file_record().total(),
+            bfc.complexity(),
+            weighting_factors.weight(bfc)
+        );
+    }
+
+    let _ = writeln!(
+        output,
+        "| | **Total** | | | | {:.2} |",
+        project.total_function_point_not_adjusted()
+    );
+    let _ = writeln!(output, "{}", APPENDIX_END);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::basic_functional_component::Complexity;
+    use crate::project::weighting_factors::WeightingFactors;
+
+    const SPEC: &str = "# Billing
+
+Customers are registered by the sales team.
+
+## Register customer {EI det=5 ftr=2}
+
+- Customer {ILF det=12 ret=3}
+- A plain bullet
+
+```
+- Not a component {EO det=1 ftr=1}
+```
+";
+
+    #[test]
+    fn should_import_tagged_items() {
+        let project = import_markdown(SPEC).unwrap();
+        let bfcs = project.basic_functional_components();
+
+        assert_eq!(project.metadata().name(), "Billing");
+        assert_eq!(bfcs.len(), 2);
+        assert_eq!(bfcs[0].name(), "Register customer");
+        assert_eq!(bfcs[0].elementary_data_referenced().total(), 5);
+        assert_eq!(bfcs[0].file_record().total(), 2);
+        assert_eq!(
+            bfcs[1].functional_classification(),
+            &FunctionalClassification::InternalLogicalFile
+        );
+        assert_eq!(bfcs[1].file_record().total(), 3);
+    }
+
+    #[test]
+    fn should_report_line_of_invalid_tags() {
+        let error = import_markdown("# Billing\n\n- Customer {ILF det=12 ftr=3}\n").unwrap_err();
+
+        assert_eq!(error.to_string(), "line 3: `ftr` does not apply to ILF");
+
+        let error = import_markdown("- Customer {EI det=x}\n").unwrap_err();
+
+        assert_eq!(error.line(), 1);
+        assert_eq!(error.message(), "`det` must be a whole number");
+    }
+
+    #[test]
+    fn should_rate_external_outputs_with_the_eo_table() {
+        let project = import_markdown("- Invoice {EO det=6 ftr=3}\n").unwrap();
+        let invoice = &project.basic_functional_components()[0];
+
+        assert_eq!(invoice.file_record().output, 3);
+        assert_eq!(invoice.complexity(), &Complexity::Middle);
+    }
+
+    #[test]
+    fn should_leave_other_braces_as_text() {
+        let project =
+            import_markdown("# Billing {#billing}\n\n## Setup {#setup}\n\n- Uses {braces}\n")
+                .unwrap();
+
+        assert_eq!(project.metadata().name(), "Billing {#billing}");
+        assert!(project.basic_functional_components().is_empty());
+    }
+
+    #[test]
+    fn should_replace_generated_appendix() {
+        let mut project = import_markdown(SPEC).unwrap();
+        let mut wf = WeightingFactors::new();
+
+        wf.set_external_input([3, 4, 6])
+            .set_referenced_logical_file([7, 10, 15]);
+        project.set_weighting_factors(wf);
+
+        let document = write_markdown_appendix(SPEC, &project);
+
+        assert!(document.contains("| EI | Register customer | 5 | 2 | Middle | 4 |\n"));
+        assert!(document.contains("| ILF | Customer | 12 | 3 | Simple | 7 |\n"));
+        assert!(document.ends_with("| | **Total** | | | | 11.00 |\n<!-- /fpa:appendix -->\n"));
+
+        assert_eq!(write_markdown_appendix(&document, &project), document);
+        assert_eq!(
+            import_markdown(&document)
+                .unwrap()
+                .basic_functional_components(),
+            project.basic_functional_components()
+        );
+    }
+}
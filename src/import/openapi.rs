@@ -0,0 +1,446 @@
+use serde_json::{Map, Value};
+
+use super::{files_for, push_unique, Confidence, ImportError, Proposal, ProposalSet};
+use crate::project::basic_functional_component::{
+    BasicFunctionalComponent, ElementaryDataReferenced, FunctionalClassification,
+};
+use crate::project::metadata::{CountType, Metadata};
+use crate::project::Project;
+
+const METHODS: [&str; 5] = ["get", "post", "put", "patch", "delete"];
+
+/// Schema extension naming the data function a schema belongs to.
+const DATA_FUNCTION_HINT: &str = "x-fpa-data-function";
+/// Operation extension listing extra data functions referenced.
+const FTR_HINT: &str = "x-fpa-ftr";
+/// Operation extension marking a GET whose response is derived, making it an EO.
+const DERIVED_HINT: &str = "x-fpa-derived";
+/// Operation extension excluding it from the count.
+const IGNORE_HINT: &str = "x-fpa-ignore";
+
+/// Read an OpenAPI 3 document, in JSON or YAML.
+fn parse(spec: &str) -> Result<Value, ImportError> {
+    if spec.trim_start().starts_with('{') {
+        serde_json::from_str(spec).map_err(|e| ImportError::new(e.line(), e.to_string()))
+    } else {
+        serde_yaml::from_str(spec).map_err(|e| {
+            let line = e.location().map_or(1, |location| location.line());
+            ImportError::new(line, e.to_string())
+        })
+    }
+}
+
+/// Propose one transactional function per operation of an OpenAPI 3 document.
+/// Counting hints are given by the `x-fpa-data-function` extension on schemas
+/// and the `x-fpa-ftr`, `x-fpa-derived` and `x-fpa-ignore` ones on operations.
+pub fn import_openapi(spec: &str) -> Result<ProposalSet, ImportError> {
+    let document = parse(spec)?;
+    let mut set = ProposalSet::new();
+    let empty = Map::new();
+    let paths = document
+        .get("paths")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+
+    for (path, item) in paths {
+        for method in METHODS.iter() {
+            let operation = match item.get(*method) {
+                Some(operation) => operation,
+                None => continue,
+            };
+
+            if operation.get(IGNORE_HINT).and_then(Value::as_bool) == Some(true) {
+                continue;
+            }
+
+            set.add(Operation::new(&document, path, method, item, operation).proposal());
+        }
+    }
+
+    Ok(set)
+}
+
+/// Draft project, named after the document's title, holding every proposal.
+pub fn draft_project_from_openapi(spec: &str) -> Result<Project, ImportError> {
+    let title = parse(spec)?
+        .pointer("/info/title")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    let mut project = import_openapi(spec)?.draft();
+
+    project.set_metadata(Metadata::new(title, CountType::Development));
+
+    Ok(project)
+}
+
+struct Operation {
+    name: String,
+    reference: String,
+    classification: FunctionalClassification,
+    input: Vec<String>,
+    output: Vec<String>,
+    file_types_referenced: Vec<String>,
+}
+
+impl Operation {
+    fn new(document: &Value, path: &str, method: &str, item: &Value, operation: &Value) -> Self {
+        let reference = format!("{} {}", method.to_uppercase(), path);
+        let name = operation
+            .get("operationId")
+            .or_else(|| operation.get("summary"))
+            .and_then(Value::as_str)
+            .map_or_else(|| reference.clone(), str::to_string);
+
+        let classification = match method {
+            "get" if operation.get(DERIVED_HINT).and_then(Value::as_bool) == Some(true) => {
+                FunctionalClassification::ExternalOutput
+            }
+            "get" => FunctionalClassification::ExternalQuery,
+            _ => FunctionalClassification::ExternalInput,
+        };
+
+        let mut collector = Collector {
+            document,
+            visited: Vec::new(),
+            file_types_referenced: Vec::new(),
+        };
+
+        let mut input = Vec::new();
+
+        for parameters in [item.get("parameters"), operation.get("parameters")]
+            .iter()
+            .flatten()
+        {
+            for parameter in parameters.as_array().into_iter().flatten() {
+                let parameter = collector.resolve(parameter);
+                let location = parameter.get("in").and_then(Value::as_str);
+
+                if let (Some("path" | "query"), Some(name)) =
+                    (location, parameter.get("name").and_then(Value::as_str))
+                {
+                    push_unique(&mut input, name);
+                }
+            }
+        }
+
+        if let Some(body) = operation.get("requestBody") {
+            let body = collector.resolve(body);
+
+            if let Some(schema) = media_schema(body) {
+                collector.collect(schema, &mut input);
+            }
+        }
+
+        let mut output = Vec::new();
+
+        if let Some(schema) = success_response(operation)
+            .map(|response| collector.resolve(response))
+            .and_then(media_schema)
+        {
+            collector.collect(schema, &mut output);
+        }
+
+        output.retain(|det| !input.iter().any(|i| i.eq_ignore_ascii_case(det)));
+
+        for hint in operation
+            .get(FTR_HINT)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            push_unique(&mut collector.file_types_referenced, hint);
+        }
+
+        Self {
+            name,
+            reference,
+            classification,
+            input,
+            output,
+            file_types_referenced: collector.file_types_referenced,
+        }
+    }
+
+    fn proposal(self) -> Proposal {
+        let file_registry = files_for(
+            &self.classification,
+            self.file_types_referenced.len() as u32,
+        );
+        let mut bfc = BasicFunctionalComponent::new(self.name, self.classification);
+
+        bfc.set_edr(ElementaryDataReferenced::new(
+            self.input.len() as u32,
+            self.output.len() as u32,
+        ))
+        .set_file_registry(file_registry)
+        .add_source_reference(&self.reference);
+
+        let mut proposal = Proposal::new(bfc);
+
+        proposal.add_rationale(format!(
+            "{} DETs from parameters and request, {} from response",
+            self.input.len(),
+            self.output.len()
+        ));
+
+        if self.file_types_referenced.is_empty() {
+            proposal
+                .add_rationale("no data function hints, FTRs need review")
+                .set_confidence(Confidence::Low);
+        } else {
+            proposal.add_rationale(format!(
+                "FTRs from hints: {}",
+                self.file_types_referenced.join(", ")
+            ));
+            proposal.set_confidence(Confidence::High);
+        }
+
+        proposal
+    }
+}
+
+struct Collector<'a> {
+    document: &'a Value,
+    visited: Vec<String>,
+    file_types_referenced: Vec<String>,
+}
+
+impl<'a> Collector<'a> {
+    /// Follow a local `$ref`, if any.
+    fn resolve(&self, value: &'a Value) -> &'a Value {
+        match value.get("$ref").and_then(Value::as_str) {
+            Some(reference) => reference
+                .strip_prefix('#')
+                .and_then(|pointer| self.document.pointer(pointer))
+                .unwrap_or(value),
+            None => value,
+        }
+    }
+
+    /// Collect the leaf property names of a schema into `dets`, and the data
+    /// functions hinted by the schemas it references. Returns whether the
+    /// schema is structured rather than a single field.
+    fn collect(&mut self, schema: &'a Value, dets: &mut Vec<String>) -> bool {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            if self.visited.iter().any(|visited| visited == reference) {
+                return true;
+            }
+
+            self.visited.push(reference.to_string());
+
+            let resolved = self.resolve(schema);
+            let structured = self.collect(resolved, dets);
+
+            self.visited.pop();
+
+            return structured;
+        }
+
+        if let Some(data_function) = schema.get(DATA_FUNCTION_HINT).and_then(Value::as_str) {
+            push_unique(&mut self.file_types_referenced, data_function);
+        }
+
+        let mut structured = false;
+
+        for composition in ["allOf", "oneOf", "anyOf"].iter() {
+            for part in schema
+                .get(*composition)
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                structured |= self.collect(part, dets);
+            }
+        }
+
+        if let Some(items) = schema.get("items") {
+            structured |= self.collect(items, dets);
+        }
+
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            structured = true;
+
+            for (name, property) in properties {
+                if !self.collect(property, dets) {
+                    push_unique(dets, name);
+                }
+            }
+        }
+
+        structured
+    }
+}
+
+fn media_schema(holder: &Value) -> Option<&Value> {
+    let content = holder.get("content")?.as_object()?;
+
+    content
+        .get("application/json")
+        .or_else(|| content.values().next())?
+        .get("schema")
+}
+
+fn success_response(operation: &Value) -> Option<&Value> {
+    let responses = operation.get("responses")?.as_object()?;
+
+    responses
+        .iter()
+        .filter(|(status, _)| status.starts_with('2'))
+        .min_by_key(|(status, _)| status.as_str())
+        .map(|(_, response)| response)
+        .or_else(|| responses.get("default"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r##"
+openapi: 3.0.3
+info:
+  title: Customer service
+  version: 1.0.0
+paths:
+  /customers:
+    get:
+      operationId: listCustomers
+      parameters:
+        - name: city
+          in: query
+        - name: X-Request-Id
+          in: header
+      responses:
+        "200":
+          description: Customers
+          content:
+            application/json:
+              schema:
+                type: array
+                items:
+                  $ref: "#/components/schemas/Customer"
+    post:
+      operationId: createCustomer
+      requestBody:
+        $ref: "#/components/requestBodies/NewCustomer"
+      responses:
+        "201":
+          description: Created
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/Customer"
+  /customers/{id}/statement:
+    parameters:
+      - name: id
+        in: path
+    get:
+      summary: Customer statement
+      x-fpa-derived: true
+      x-fpa-ftr: [Invoice]
+      responses:
+        "200":
+          description: Statement
+          content:
+            application/json:
+              schema:
+                type: object
+                properties:
+                  customer:
+                    $ref: "#/components/schemas/Customer"
+                  balance:
+                    type: number
+  /health:
+    get:
+      x-fpa-ignore: true
+      responses:
+        "200":
+          description: OK
+components:
+  requestBodies:
+    NewCustomer:
+      content:
+        application/json:
+          schema:
+            type: object
+            x-fpa-data-function: Customer
+            properties:
+              name: { type: string }
+              address:
+                $ref: "#/components/schemas/Address"
+  schemas:
+    Customer:
+      x-fpa-data-function: Customer
+      allOf:
+        - type: object
+          properties:
+            id: { type: integer }
+            name: { type: string }
+        - type: object
+          properties:
+            address:
+              $ref: "#/components/schemas/Address"
+    Address:
+      type: object
+      properties:
+        street: { type: string }
+        city: { type: string }
+"##;
+
+    #[test]
+    fn should_propose_transactions() {
+        let set = import_openapi(SPEC).unwrap();
+        let components: Vec<_> = set.proposals().iter().map(Proposal::component).collect();
+
+        assert_eq!(components.len(), 3);
+        assert_eq!(set.proposals()[2].confidence(), Confidence::High);
+
+        assert_eq!(components[0].name(), "listCustomers");
+        assert_eq!(
+            components[0].functional_classification(),
+            &FunctionalClassification::ExternalQuery
+        );
+        assert_eq!(components[0].elementary_data_referenced().input(), &1);
+        assert_eq!(components[0].elementary_data_referenced().output(), &3);
+        assert_eq!(components[0].file_record().total(), 1);
+
+        assert_eq!(components[1].name(), "createCustomer");
+        assert_eq!(
+            components[1].functional_classification(),
+            &FunctionalClassification::ExternalInput
+        );
+        assert_eq!(components[1].elementary_data_referenced().input(), &3);
+        assert_eq!(components[1].elementary_data_referenced().output(), &1);
+        assert_eq!(
+            components[1].traceability().source_references(),
+            ["POST /customers"]
+        );
+
+        assert_eq!(components[2].name(), "Customer statement");
+        assert_eq!(
+            components[2].functional_classification(),
+            &FunctionalClassification::ExternalOutput
+        );
+        assert_eq!(components[2].elementary_data_referenced().total(), 5);
+        assert_eq!(components[2].file_record().output, 2);
+        assert_eq!(
+            set.proposals()[2].rationale()[1],
+            "FTRs from hints: Customer, Invoice"
+        );
+    }
+
+    #[test]
+    fn should_draft_project_from_openapi() {
+        let json = r#"{"info": {"title": "Empty"}, "paths": {"/ping": {"delete": {}}}}"#;
+        let project = draft_project_from_openapi(json).unwrap();
+
+        assert_eq!(project.metadata().name(), "Empty");
+        assert_eq!(
+            project.basic_functional_components()[0].name(),
+            "DELETE /ping"
+        );
+
+        assert_eq!(import_openapi("{\n\"paths\": [").unwrap_err().line(), 2);
+    }
+}
@@ -0,0 +1,699 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::sql::{group_tables, Table};
+use super::ProposalSet;
+
+/// Propose one ILF per logical file declared by the Diesel `table!` macros
+/// and SeaORM entities of a crate, grouping tables related by `joinable!` or
+/// `belongs_to` the same way as [`import_ddl`](super::sql::import_ddl).
+pub fn import_rust_crate(directory: impl AsRef<Path>) -> io::Result<ProposalSet> {
+    let mut set = ProposalSet::new();
+
+    for file in group_tables(&scan_rust_crate(directory)?) {
+        set.add(file.proposal());
+    }
+
+    Ok(set)
+}
+
+/// Tables declared by the Rust sources of a crate, skipping `target` and
+/// hidden directories.
+pub fn scan_rust_crate(directory: impl AsRef<Path>) -> io::Result<Vec<Table>> {
+    let mut scan = Scan::default();
+
+    visit_rust_files(directory.as_ref(), &mut |module, source| {
+        scan.source(module, source)
+    })?;
+
+    Ok(scan.tables())
+}
+
+/// Call `visit` with the module name and content of every Rust source under
+/// `directory`, in path order, skipping `target` and hidden directories.
+pub(super) fn visit_rust_files(
+    directory: &Path,
+    visit: &mut dyn FnMut(&str, &str),
+) -> io::Result<()> {
+    let mut entries = fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    entries.sort();
+
+    for path in entries {
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+
+        if path.is_dir() {
+            if name != "target" && !name.starts_with('.') {
+                visit_rust_files(&path, visit)?;
+            }
+        } else if let Some(module) = name.strip_suffix(".rs") {
+            visit(module, &fs::read_to_string(&path)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// SeaORM `belongs_to` relation, resolved once every entity is known.
+struct Relation {
+    table: String,
+    column: String,
+    module: String,
+}
+
+#[derive(Default)]
+struct Scan {
+    tables: Vec<Table>,
+    joinables: Vec<(String, String, String)>,
+    relations: Vec<Relation>,
+    entity_modules: Vec<(String, String)>,
+}
+
+impl Scan {
+    fn source(&mut self, module: &str, source: &str) {
+        let tokens = tokenize(source);
+        let mut i = 0;
+        let mut attributes: Vec<(String, String)> = Vec::new();
+        let mut entity: Option<String> = None;
+
+        while i < tokens.len() {
+            let token = tokens[i].as_str();
+
+            if token == "#" && tokens.get(i + 1).map(String::as_str) == Some("[") {
+                let (arguments, end) = attribute(&tokens, i);
+
+                attributes.extend(arguments);
+                i = end;
+                continue;
+            }
+
+            let is_macro =
+                |name: &str| token == name && tokens.get(i + 1).map(String::as_str) == Some("!");
+
+            if is_macro("table") {
+                i = self.diesel_table(&tokens, i + 2);
+            } else if is_macro("joinable") {
+                // joinable!(child -> parent (foreign_key));
+                if let [_, child, _, parent, _, column, ..] = &tokens[i + 2..] {
+                    self.joinables
+                        .push((child.clone(), parent.clone(), column.clone()));
+                }
+                i += 2;
+            } else if token == "struct" && tokens.get(i + 1).map(String::as_str) == Some("Model") {
+                if let Some((_, table_name)) =
+                    attributes.iter().find(|(key, _)| key == "table_name")
+                {
+                    self.entity_modules
+                        .push((module.to_string(), table_name.clone()));
+                    entity = Some(table_name.clone());
+                    i = self.seaorm_model(&tokens, i + 2, table_name.clone());
+                } else {
+                    i += 2;
+                }
+            } else if token == "enum" && tokens.get(i + 1).map(String::as_str) == Some("Relation") {
+                i = self.seaorm_relations(&tokens, i + 2, entity.clone());
+            } else {
+                if !matches!(token, "pub" | "(" | ")" | "crate" | "struct" | "enum") {
+                    attributes.clear();
+                }
+                i += 1;
+            }
+        }
+    }
+
+    /// Read `name (keys) { column -> Type, ... }` from the body of a `table!`.
+    fn diesel_table(&mut self, tokens: &[String], start: usize) -> usize {
+        let end = match closing(tokens, start) {
+            Some(end) => end,
+            None => return tokens.len(),
+        };
+        let body = &tokens[start + 1..end - 1];
+        let mut i = 0;
+
+        while i < body.len() && body[i] == "#" {
+            i = attribute(body, i).1;
+        }
+
+        let mut name = match body.get(i) {
+            Some(name) => name.clone(),
+            None => return end,
+        };
+
+        i += 1;
+
+        while body.get(i).map(String::as_str) == Some(".") {
+            if let Some(next) = body.get(i + 1) {
+                name = next.clone();
+            }
+            i += 2;
+        }
+
+        let mut table = Table::new(name);
+        let mut primary_key = Vec::new();
+
+        if body.get(i).map(String::as_str) == Some("(") {
+            let keys_end = match closing(body, i) {
+                Some(keys_end) => keys_end,
+                None => return end,
+            };
+
+            primary_key.extend(
+                body[i + 1..keys_end - 1]
+                    .iter()
+                    .filter(|key| *key != ",")
+                    .cloned(),
+            );
+            i = keys_end;
+        }
+
+        if primary_key.is_empty() {
+            primary_key.push("id".to_string());
+        }
+
+        for key in primary_key {
+            table.add_primary_key(key);
+        }
+
+        if body.get(i).map(String::as_str) == Some("{") {
+            let columns_end = match closing(body, i) {
+                Some(columns_end) => columns_end,
+                None => return end,
+            };
+            let columns = &body[i + 1..columns_end - 1];
+            let mut j = 0;
+            let mut sql_name = None;
+
+            while j < columns.len() {
+                if columns[j] == "#" {
+                    let (arguments, next) = attribute(columns, j);
+
+                    sql_name = arguments
+                        .into_iter()
+                        .find(|(key, _)| key == "sql_name")
+                        .map(|(_, value)| value);
+                    j = next;
+                } else if columns.get(j + 1).map(String::as_str) == Some("->") {
+                    table.add_column(sql_name.take().unwrap_or_else(|| columns[j].clone()));
+                    j = skip_to_comma(columns, j + 2);
+                } else {
+                    j += 1;
+                }
+            }
+        }
+
+        self.tables.push(table);
+
+        end
+    }
+
+    /// Read the fields of a SeaORM `Model` struct.
+    fn seaorm_model(&mut self, tokens: &[String], start: usize, name: String) -> usize {
+        if tokens.get(start).map(String::as_str) != Some("{") {
+            return start;
+        }
+
+        let end = match closing(tokens, start) {
+            Some(end) => end,
+            None => return tokens.len(),
+        };
+        let fields = &tokens[start + 1..end - 1];
+        let mut table = Table::new(name);
+        let mut field_attributes = Vec::new();
+        let mut i = 0;
+
+        while i < fields.len() {
+            if fields[i] == "#" {
+                let (arguments, next) = attribute(fields, i);
+
+                field_attributes.extend(arguments);
+                i = next;
+            } else if fields.get(i + 1).map(String::as_str) == Some(":") {
+                let has = |key: &str| field_attributes.iter().any(|(k, _)| k == key);
+
+                if !has("ignore") {
+                    if has("primary_key") {
+                        table.add_primary_key(fields[i].clone());
+                    }
+
+                    let column = field_attributes
+                        .iter()
+                        .find(|(key, _)| key == "column_name")
+                        .map_or_else(|| fields[i].clone(), |(_, value)| value.clone());
+
+                    table.add_column(column);
+                }
+
+                field_attributes.clear();
+                i = skip_to_comma(fields, i + 2);
+            } else {
+                i += 1;
+            }
+        }
+
+        self.tables.push(table);
+
+        end
+    }
+
+    /// Read the `belongs_to` variants of a SeaORM `Relation` enum.
+    fn seaorm_relations(
+        &mut self,
+        tokens: &[String],
+        start: usize,
+        entity: Option<String>,
+    ) -> usize {
+        if tokens.get(start).map(String::as_str) != Some("{") {
+            return start;
+        }
+
+        let end = match closing(tokens, start) {
+            Some(end) => end,
+            None => return tokens.len(),
+        };
+        let table = match entity {
+            Some(table) => table,
+            None => return end,
+        };
+        let mut i = start + 1;
+
+        while i < end {
+            if tokens[i] == "#" {
+                let (arguments, next) = attribute(tokens, i);
+                let value = |key: &str| {
+                    arguments
+                        .iter()
+                        .find(|(k, _)| k == key)
+                        .map(|(_, value)| value.clone())
+                };
+
+                if let (Some(entity), Some(from)) = (value("belongs_to"), value("from")) {
+                    let mut path = entity.rsplit("::").skip(1);
+
+                    self.relations.push(Relation {
+                        table: table.clone(),
+                        column: snake_case(from.rsplit("::").next().unwrap_or_default()),
+                        module: path.next().unwrap_or_default().to_string(),
+                    });
+                }
+
+                i = next;
+            } else {
+                i += 1;
+            }
+        }
+
+        end
+    }
+
+    fn tables(mut self) -> Vec<Table> {
+        for (child, parent, column) in &self.joinables {
+            if let Some(table) = self.tables.iter_mut().find(|table| table.name() == child) {
+                table.add_foreign_key(vec![column.clone()], parent.clone());
+            }
+        }
+
+        for relation in &self.relations {
+            let parent = self
+                .entity_modules
+                .iter()
+                .find(|(module, _)| *module == relation.module)
+                .map_or_else(|| relation.module.clone(), |(_, table)| table.clone());
+
+            if let Some(table) = self
+                .tables
+                .iter_mut()
+                .find(|table| table.name() == relation.table)
+            {
+                table.add_foreign_key(vec![relation.column.clone()], parent);
+            }
+        }
+
+        self.tables
+    }
+}
+
+/// Arguments of the `#[...]` attribute starting at `start`, as key and
+/// string value pairs, with the position following it.
+fn attribute(tokens: &[String], start: usize) -> (Vec<(String, String)>, usize) {
+    let end = match closing(tokens, start + 1) {
+        Some(end) => end,
+        None => return (Vec::new(), tokens.len()),
+    };
+    let body = &tokens[start + 2..end - 1];
+    let mut arguments = Vec::new();
+
+    for (i, token) in body.iter().enumerate() {
+        if token.starts_with('"') || token == "=" || token == "," || token == "(" || token == ")" {
+            continue;
+        }
+
+        match (body.get(i + 1).map(String::as_str), body.get(i + 2)) {
+            (Some("="), Some(value)) if value.starts_with('"') => {
+                arguments.push((token.clone(), value.trim_matches('"').to_string()));
+            }
+            (Some("="), _) => {}
+            _ if i > 0 && body[i - 1] == "=" => {}
+            _ => arguments.push((token.clone(), String::new())),
+        }
+    }
+
+    (arguments, end)
+}
+
+/// Position following the group opened at `start`, or `None` if the source
+/// ends before the group is closed.
+pub(super) fn closing(tokens: &[String], start: usize) -> Option<usize> {
+    let mut depth = 0;
+
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        match token.as_str() {
+            "(" | "[" | "{" => depth += 1,
+            ")" | "]" | "}" => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Position following the next `,` outside of any generic or group.
+pub(super) fn skip_to_comma(tokens: &[String], start: usize) -> usize {
+    let mut depth = 0;
+    let mut i = start;
+
+    while i < tokens.len() {
+        match tokens[i].as_str() {
+            "<" | "(" | "[" => depth += 1,
+            ">" | ")" | "]" => depth -= 1,
+            "," if depth == 0 => return i + 1,
+            _ => {}
+        }
+        i += 1;
+    }
+
+    i
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}
+
+/// Split Rust source into identifiers, string literals, `->`, `::` and
+/// single punctuation, dropping comments.
+pub(super) fn tokenize(source: &str) -> Vec<String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            let mut depth = 0;
+
+            while i < chars.len() {
+                if chars[i] == '/' && chars.get(i + 1) == Some(&'*') {
+                    depth += 1;
+                    i += 2;
+                } else if chars[i] == '*' && chars.get(i + 1) == Some(&'/') {
+                    depth -= 1;
+                    i += 2;
+
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    i += 1;
+                }
+            }
+        } else if c == '"'
+            || (c == 'r' && next == Some('"'))
+            || (c == 'r' && next == Some('#') && matches!(chars.get(i + 2), Some('"' | '#')))
+        {
+            let raw = c == 'r';
+            let mut hashes = 0;
+
+            if raw {
+                i += 1;
+
+                while chars.get(i) == Some(&'#') {
+                    hashes += 1;
+                    i += 1;
+                }
+            }
+
+            let mut text = String::from('"');
+
+            i += 1;
+
+            while i < chars.len() {
+                if !raw && chars[i] == '\\' {
+                    text.push(chars.get(i + 1).copied().unwrap_or_default());
+                    i += 2;
+                } else if chars[i] == '"' && (0..hashes).all(|h| chars.get(i + 1 + h) == Some(&'#'))
+                {
+                    i += 1 + hashes;
+                    break;
+                } else {
+                    text.push(chars[i]);
+                    i += 1;
+                }
+            }
+
+            text.push('"');
+            tokens.push(text);
+        } else if c == '\'' {
+            // Char literal or lifetime, neither matters here.
+            if next == Some('\\') {
+                i += 4;
+            } else if chars.get(i + 2) == Some(&'\'') {
+                i += 3;
+            } else {
+                i += 1;
+            }
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = i;
+
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+
+            tokens.push(chars[start..i].iter().collect());
+        } else if (c == '-' && next == Some('>')) || (c == ':' && next == Some(':')) {
+            tokens.push(chars[i..i + 2].iter().collect());
+            i += 2;
+        } else {
+            tokens.push(c.to_string());
+            i += 1;
+        }
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIESEL: &str = r#"
+        // @generated automatically by Diesel CLI.
+
+        diesel::table! {
+            /// Registered users.
+            users (id) {
+                id -> Int4,
+                name -> Varchar,
+                #[sql_name = "e-mail"]
+                email -> Nullable<Varchar>,
+            }
+        }
+
+        diesel::table! {
+            user_settings (user_id, key) {
+                user_id -> Int4,
+                key -> Text,
+                value -> Array<Nullable<Text>>,
+            }
+        }
+
+        table! {
+            posts {
+                id -> Int4,
+                user_id -> Int4,
+                title -> Varchar,
+            }
+        }
+
+        diesel::joinable!(posts -> users (user_id));
+        diesel::joinable!(user_settings -> users (user_id));
+        diesel::allow_tables_to_appear_in_same_query!(posts, users, user_settings);
+    "#;
+
+    const CAKE: &str = r#"
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "cake")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub name: String,
+            #[sea_orm(ignore)]
+            pub cached: Option<HashMap<String, i32>>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(has_many = "super::cake_topping::Entity")]
+            CakeTopping,
+        }
+    "#;
+
+    const CAKE_TOPPING: &str = r#"
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "cake_toppings")]
+        pub struct Model {
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub cake_id: i32,
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub topping: String,
+            #[sea_orm(column_name = "qty")]
+            pub quantity: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(
+                belongs_to = "super::cake::Entity",
+                from = "Column::CakeId",
+                to = "super::cake::Column::Id"
+            )]
+            Cake,
+        }
+    "#;
+
+    fn fixture(name: &str, files: &[(&str, &str)]) -> std::path::PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("fpa-rs-{}-{}", name, std::process::id()));
+
+        for (path, content) in files {
+            let path = directory.join(path);
+
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+
+        directory
+    }
+
+    #[test]
+    fn should_scan_diesel_schema() {
+        let directory = fixture(
+            "diesel",
+            &[
+                ("src/schema.rs", DIESEL),
+                ("target/debug/schema.rs", DIESEL),
+            ],
+        );
+        let tables = scan_rust_crate(&directory).unwrap();
+        let set = import_rust_crate(&directory).unwrap();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(tables.len(), 3);
+        assert_eq!(tables[0].columns(), ["id", "name", "e-mail"]);
+        assert_eq!(tables[1].primary_key(), ["user_id", "key"]);
+        assert_eq!(tables[2].primary_key(), ["id"]);
+        assert_eq!(tables[2].foreign_keys()[0].references, "users");
+
+        let names: Vec<_> = set
+            .proposals()
+            .iter()
+            .map(|proposal| proposal.component().name().as_str())
+            .collect();
+
+        assert_eq!(names, vec!["users", "posts"]);
+
+        let users = set.proposals()[0].component();
+
+        assert_eq!(users.elementary_data_referenced().total(), 5);
+        assert_eq!(users.file_record().total(), 2);
+    }
+
+    #[test]
+    fn should_scan_seaorm_entities() {
+        let directory = fixture(
+            "seaorm",
+            &[
+                ("src/entity/cake.rs", CAKE),
+                ("src/entity/cake_topping.rs", CAKE_TOPPING),
+            ],
+        );
+        let set = import_rust_crate(&directory).unwrap();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(set.proposals().len(), 1);
+
+        let cake = set.proposals()[0].component();
+
+        assert_eq!(cake.name(), "cake");
+        assert_eq!(cake.elementary_data_referenced().total(), 4);
+        assert_eq!(cake.file_record().total(), 2);
+        assert_eq!(
+            set.proposals()[0].rationale(),
+            ["cake_toppings grouped into cake as a RET"]
+        );
+    }
+
+    #[test]
+    fn should_skip_truncated_items() {
+        let directory = fixture(
+            "truncated",
+            &[
+                ("src/schema.rs", DIESEL),
+                ("src/half_table.rs", "table! { users (id"),
+                (
+                    "src/entity/half_model.rs",
+                    "#[sea_orm(table_name = \"cake\")]\npub struct Model {",
+                ),
+                ("src/half_attribute.rs", "#[sea_orm(table_name"),
+            ],
+        );
+        let tables = scan_rust_crate(&directory).unwrap();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        let names: Vec<_> = tables.iter().map(Table::name).collect();
+
+        assert_eq!(names, ["users", "user_settings", "posts"]);
+    }
+}
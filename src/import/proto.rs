@@ -0,0 +1,743 @@
+use super::{files_for, push_unique, Confidence, ImportError, Proposal, ProposalSet};
+use crate::project::basic_functional_component::{
+    BasicFunctionalComponent, ElementaryDataReferenced, FunctionalClassification,
+};
+use crate::project::metadata::{CountType, Metadata};
+use crate::project::Project;
+
+/// Method option overriding the naming conventions with `EI`, `EO`, `EQ` or `IGNORE`.
+const CLASSIFICATION_OPTION: &str = "(fpa.classification)";
+/// Method option listing the data functions it references, separated by commas.
+const FTR_OPTION: &str = "(fpa.ftr)";
+/// Message option naming the data function the message belongs to.
+const DATA_FUNCTION_OPTION: &str = "(fpa.data_function)";
+
+const INPUT_PREFIXES: [&str; 17] = [
+    "Create", "Add", "Insert", "Update", "Upsert", "Set", "Patch", "Put", "Save", "Delete",
+    "Remove", "Cancel", "Submit", "Register", "Approve", "Reject", "Import",
+];
+const OUTPUT_PREFIXES: [&str; 10] = [
+    "Calculate",
+    "Compute",
+    "Generate",
+    "Report",
+    "Export",
+    "Summarize",
+    "Aggregate",
+    "Watch",
+    "Stream",
+    "Subscribe",
+];
+const QUERY_PREFIXES: [&str; 10] = [
+    "Get", "List", "Find", "Search", "Read", "Fetch", "Lookup", "Query", "Describe", "Check",
+];
+
+/// Propose one transactional function per RPC of a `.proto` file, classified
+/// by the `(fpa.classification)` option or else by the method's verb.
+pub fn import_proto(source: &str) -> Result<ProposalSet, ImportError> {
+    let file = ProtoFile::parse(source)?;
+    let mut set = ProposalSet::new();
+
+    for rpc in &file.rpcs {
+        if let Some(proposal) = file.proposal(rpc) {
+            set.add(proposal);
+        }
+    }
+
+    Ok(set)
+}
+
+/// Draft project, named after the file's package, holding every proposal.
+pub fn draft_project_from_proto(source: &str) -> Result<Project, ImportError> {
+    let package = ProtoFile::parse(source)?.package;
+    let mut project = import_proto(source)?.draft();
+
+    project.set_metadata(Metadata::new(package, CountType::Development));
+
+    Ok(project)
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct MessageField {
+    name: String,
+    type_name: String,
+}
+
+#[derive(PartialEq, Debug, Clone, Default)]
+struct Message {
+    name: String,
+    fields: Vec<MessageField>,
+    data_function: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct Rpc {
+    service: String,
+    name: String,
+    request: String,
+    response: String,
+    server_streaming: bool,
+    options: Vec<(String, String)>,
+}
+
+impl Rpc {
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .find(|(option, _)| option == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Default)]
+struct ProtoFile {
+    package: String,
+    messages: Vec<Message>,
+    rpcs: Vec<Rpc>,
+}
+
+/// Last segment of a possibly qualified type name.
+fn simple_name(type_name: &str) -> &str {
+    type_name.rsplit('.').next().unwrap_or(type_name)
+}
+
+fn has_prefix(name: &str, prefixes: &[&str]) -> bool {
+    prefixes.iter().any(|prefix| {
+        name.strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_uppercase))
+    })
+}
+
+impl ProtoFile {
+    fn parse(source: &str) -> Result<Self, ImportError> {
+        let mut parser = Parser {
+            tokens: tokenize(source)?,
+            position: 0,
+        };
+        let mut file = ProtoFile::default();
+
+        while let Some(token) = parser.next() {
+            match token.as_str() {
+                "package" => file.package = parser.identifier()?,
+                "syntax" | "edition" | "import" | "option" => parser.skip_statement()?,
+                "message" => parser.message(&mut file.messages)?,
+                "enum" | "extend" => {
+                    parser.identifier()?;
+                    parser.skip_block()?;
+                }
+                "service" => parser.service(&mut file.rpcs)?,
+                ";" => {}
+                other => return Err(parser.error(format!("unexpected `{}`", other))),
+            }
+        }
+
+        Ok(file)
+    }
+
+    fn message(&self, type_name: &str) -> Option<&Message> {
+        let name = simple_name(type_name);
+
+        self.messages.iter().find(|message| message.name == name)
+    }
+
+    /// Flatten the fields of a message into leaf DETs, collecting the data
+    /// functions of the messages met on the way.
+    fn flatten(
+        &self,
+        type_name: &str,
+        visited: &mut Vec<String>,
+        dets: &mut Vec<String>,
+        data_functions: &mut Vec<String>,
+    ) {
+        let message = match self.message(type_name) {
+            Some(message) => message,
+            None => return,
+        };
+
+        if visited.contains(&message.name) {
+            return;
+        }
+
+        visited.push(message.name.clone());
+
+        if let Some(data_function) = &message.data_function {
+            push_unique(data_functions, data_function);
+        }
+
+        for field in &message.fields {
+            if self.message(&field.type_name).is_some() {
+                self.flatten(&field.type_name, visited, dets, data_functions);
+            } else {
+                push_unique(dets, &field.name);
+            }
+        }
+
+        visited.pop();
+    }
+
+    fn proposal(&self, rpc: &Rpc) -> Option<Proposal> {
+        let (classification, confidence, rationale) =
+            match rpc.option(CLASSIFICATION_OPTION).map(str::to_uppercase) {
+                Some(option) => {
+                    let classification = match option.as_str() {
+                        "EI" => FunctionalClassification::ExternalInput,
+                        "EO" => FunctionalClassification::ExternalOutput,
+                        "EQ" => FunctionalClassification::ExternalQuery,
+                        _ => return None,
+                    };
+
+                    (classification, Confidence::High, "classified by option")
+                }
+                None if has_prefix(&rpc.name, &INPUT_PREFIXES) => (
+                    FunctionalClassification::ExternalInput,
+                    Confidence::Medium,
+                    "classified by naming convention",
+                ),
+                None if has_prefix(&rpc.name, &OUTPUT_PREFIXES) || rpc.server_streaming => (
+                    FunctionalClassification::ExternalOutput,
+                    Confidence::Medium,
+                    "classified by naming convention",
+                ),
+                None if has_prefix(&rpc.name, &QUERY_PREFIXES) => (
+                    FunctionalClassification::ExternalQuery,
+                    Confidence::Medium,
+                    "classified by naming convention",
+                ),
+                None => (
+                    FunctionalClassification::ExternalInput,
+                    Confidence::Low,
+                    "no naming convention matched, classification needs review",
+                ),
+            };
+
+        let mut input = Vec::new();
+        let mut output = Vec::new();
+        let mut data_functions = Vec::new();
+
+        self.flatten(
+            &rpc.request,
+            &mut Vec::new(),
+            &mut input,
+            &mut data_functions,
+        );
+        self.flatten(
+            &rpc.response,
+            &mut Vec::new(),
+            &mut output,
+            &mut data_functions,
+        );
+        output.retain(|det| !input.contains(det));
+
+        for data_function in rpc
+            .option(FTR_OPTION)
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+        {
+            push_unique(&mut data_functions, data_function);
+        }
+
+        let reference = if self.package.is_empty() {
+            format!("{}/{}", rpc.service, rpc.name)
+        } else {
+            format!("{}.{}/{}", self.package, rpc.service, rpc.name)
+        };
+        let file_registry = files_for(&classification, data_functions.len() as u32);
+        let mut bfc = BasicFunctionalComponent::new(&rpc.name, classification);
+
+        bfc.set_edr(ElementaryDataReferenced::new(
+            input.len() as u32,
+            output.len() as u32,
+        ))
+        .set_file_registry(file_registry)
+        .add_source_reference(reference);
+
+        let mut proposal = Proposal::new(bfc);
+
+        proposal
+            .add_rationale(rationale)
+            .add_rationale(format!(
+                "{} DETs from {}, {} from {}",
+                input.len(),
+                rpc.request,
+                output.len(),
+                rpc.response
+            ))
+            .set_confidence(confidence);
+
+        if data_functions.is_empty() {
+            proposal
+                .add_rationale("no data function options, FTRs need review")
+                .set_confidence(Confidence::Low);
+        }
+
+        Some(proposal)
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct Token {
+    text: String,
+    line: usize,
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, ImportError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = line;
+
+        if c == '\n' {
+            line += 1;
+            i += 1;
+        } else if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+
+            loop {
+                match chars.get(i) {
+                    None => return Err(ImportError::new(start, "unterminated comment")),
+                    Some('*') if chars.get(i + 1) == Some(&'/') => break,
+                    Some('\n') => line += 1,
+                    _ => {}
+                }
+                i += 1;
+            }
+
+            i += 2;
+        } else if c == '"' || c == '\'' {
+            let mut text = String::new();
+
+            i += 1;
+
+            loop {
+                match chars.get(i) {
+                    None | Some('\n') => {
+                        return Err(ImportError::new(start, "unterminated string"))
+                    }
+                    Some(&q) if q == c => break,
+                    Some('\\') => {
+                        i += 1;
+                        if let Some(&escaped) = chars.get(i) {
+                            text.push(escaped);
+                        }
+                    }
+                    Some(&other) => text.push(other),
+                }
+                i += 1;
+            }
+
+            i += 1;
+            tokens.push(Token {
+                text: format!("\"{}", text),
+                line: start,
+            });
+        } else if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' || c == '+' {
+            let mut word = String::new();
+
+            while i < chars.len() && (chars[i].is_alphanumeric() || "_.-+".contains(chars[i])) {
+                word.push(chars[i]);
+                i += 1;
+            }
+
+            tokens.push(Token {
+                text: word,
+                line: start,
+            });
+        } else {
+            tokens.push(Token {
+                text: c.to_string(),
+                line: start,
+            });
+            i += 1;
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&str> {
+        self.tokens
+            .get(self.position)
+            .map(|token| token.text.as_str())
+    }
+
+    fn next(&mut self) -> Option<String> {
+        let token = self.peek()?.to_string();
+
+        self.position += 1;
+
+        Some(token)
+    }
+
+    fn error(&self, message: impl Into<String>) -> ImportError {
+        let line = self
+            .tokens
+            .get(self.position)
+            .or_else(|| self.tokens.last())
+            .map_or(1, |token| token.line);
+
+        ImportError::new(line, message)
+    }
+
+    fn eat(&mut self, expected: &str) -> bool {
+        let found = self.peek() == Some(expected);
+
+        if found {
+            self.position += 1;
+        }
+
+        found
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), ImportError> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{}`", expected)))
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, ImportError> {
+        match self.peek() {
+            Some(token)
+                if token.starts_with(|c: char| c.is_alphabetic() || c == '_' || c == '.') =>
+            {
+                Ok(self.next().unwrap_or_default())
+            }
+            _ => Err(self.error("expected a name")),
+        }
+    }
+
+    /// Skip up to and including the next `;`, or a `{ }` block.
+    fn skip_statement(&mut self) -> Result<(), ImportError> {
+        loop {
+            match self.peek() {
+                None => return Err(self.error("expected `;`")),
+                Some(";") => {
+                    self.position += 1;
+                    return Ok(());
+                }
+                Some("{") => return self.skip_block(),
+                _ => self.position += 1,
+            }
+        }
+    }
+
+    fn skip_block(&mut self) -> Result<(), ImportError> {
+        self.expect("{")?;
+
+        let mut depth = 1;
+
+        while depth > 0 {
+            match self.next().as_deref() {
+                None => return Err(self.error("unclosed `{`")),
+                Some("{") => depth += 1,
+                Some("}") => depth -= 1,
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read `option name = value;`, after the `option` keyword.
+    fn option(&mut self) -> Result<(String, String), ImportError> {
+        let mut name = String::new();
+
+        while !self.eat("=") {
+            name.push_str(&self.next().ok_or_else(|| self.error("expected `=`"))?);
+        }
+
+        let value = if self.peek() == Some("{") {
+            self.skip_block()?;
+            String::new()
+        } else {
+            let value = self.next().ok_or_else(|| self.error("expected a value"))?;
+            value.strip_prefix('"').unwrap_or(&value).to_string()
+        };
+
+        self.eat(";");
+
+        Ok((name, value))
+    }
+
+    fn message(&mut self, messages: &mut Vec<Message>) -> Result<(), ImportError> {
+        let mut message = Message {
+            name: self.identifier()?,
+            ..Message::default()
+        };
+
+        self.expect("{")?;
+        self.message_body(&mut message, messages)?;
+        messages.push(message);
+
+        Ok(())
+    }
+
+    fn message_body(
+        &mut self,
+        message: &mut Message,
+        messages: &mut Vec<Message>,
+    ) -> Result<(), ImportError> {
+        loop {
+            let token = self.next().ok_or_else(|| self.error("unclosed `{`"))?;
+
+            match token.as_str() {
+                "}" => return Ok(()),
+                ";" => {}
+                "message" => self.message(messages)?,
+                "enum" | "extend" => {
+                    self.identifier()?;
+                    self.skip_block()?;
+                }
+                "oneof" => {
+                    self.identifier()?;
+                    self.expect("{")?;
+                    self.message_body(message, messages)?;
+                }
+                "option" => {
+                    let (name, value) = self.option()?;
+
+                    if name == DATA_FUNCTION_OPTION {
+                        message.data_function = Some(value);
+                    }
+                }
+                "reserved" | "extensions" => self.skip_statement()?,
+                "map" => {
+                    self.expect("<")?;
+                    while !self.eat(">") {
+                        self.next().ok_or_else(|| self.error("expected `>`"))?;
+                    }
+                    self.field(message, "map".to_string())?;
+                }
+                "repeated" | "optional" | "required" => {
+                    let type_name = self.identifier()?;
+                    self.field(message, type_name)?;
+                }
+                _ => {
+                    self.position -= 1;
+                    let type_name = self.identifier()?;
+                    self.field(message, type_name)?;
+                }
+            }
+        }
+    }
+
+    /// Read the rest of a field after its type.
+    fn field(&mut self, message: &mut Message, type_name: String) -> Result<(), ImportError> {
+        let name = self.identifier()?;
+
+        self.expect("=")?;
+        self.next()
+            .ok_or_else(|| self.error("expected a field number"))?;
+
+        if self.eat("[") {
+            while !self.eat("]") {
+                self.next().ok_or_else(|| self.error("expected `]`"))?;
+            }
+        }
+
+        self.expect(";")?;
+        message.fields.push(MessageField { name, type_name });
+
+        Ok(())
+    }
+
+    fn service(&mut self, rpcs: &mut Vec<Rpc>) -> Result<(), ImportError> {
+        let service = self.identifier()?;
+
+        self.expect("{")?;
+
+        loop {
+            let token = self.next().ok_or_else(|| self.error("unclosed `{`"))?;
+
+            match token.as_str() {
+                "}" => return Ok(()),
+                ";" => {}
+                "option" => {
+                    self.option()?;
+                }
+                "rpc" => {
+                    let name = self.identifier()?;
+
+                    self.expect("(")?;
+                    self.eat("stream");
+                    let request = self.identifier()?;
+                    self.expect(")")?;
+
+                    if !self.eat("returns") {
+                        return Err(self.error("expected `returns`"));
+                    }
+
+                    self.expect("(")?;
+                    let server_streaming = self.eat("stream");
+                    let response = self.identifier()?;
+                    self.expect(")")?;
+
+                    let mut options = Vec::new();
+
+                    if self.eat("{") {
+                        loop {
+                            match self.next().as_deref() {
+                                None => return Err(self.error("unclosed `{`")),
+                                Some("}") => break,
+                                Some("option") => options.push(self.option()?),
+                                _ => {}
+                            }
+                        }
+                    } else {
+                        self.expect(";")?;
+                    }
+
+                    rpcs.push(Rpc {
+                        service: service.clone(),
+                        name,
+                        request,
+                        response,
+                        server_streaming,
+                        options,
+                    });
+                }
+                other => return Err(self.error(format!("unexpected `{}`", other))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROTO: &str = r#"
+        syntax = "proto3";
+
+        package billing.v1;
+
+        import "google/protobuf/timestamp.proto";
+
+        option go_package = "example.com/billing/v1";
+
+        message Invoice {
+          option (fpa.data_function) = "Invoice";
+
+          string id = 1;
+          Customer customer = 2;
+          repeated Line lines = 3 [packed = true];
+          google.protobuf.Timestamp issued_at = 4;
+          map<string, string> labels = 5;
+
+          message Line {
+            string product = 1;
+            int32 quantity = 2;
+          }
+
+          enum Status { DRAFT = 0; PAID = 1; }
+          Status status = 6;
+
+          oneof payment {
+            string card = 7;
+            string pix = 8;
+          }
+        }
+
+        message Customer {
+          option (fpa.data_function) = "Customer";
+          string customer_id = 1;
+          string name = 2;
+        }
+
+        message CreateInvoiceRequest { Invoice invoice = 1; }
+        message GetInvoiceRequest { string id = 1; }
+        message RevenueRequest { int32 month = 1; }
+        message RevenueResponse { double total = 1; }
+        message Empty {}
+
+        /* Invoices of the billing domain. */
+        service InvoiceService {
+          rpc CreateInvoice(CreateInvoiceRequest) returns (Invoice);
+          rpc GetInvoice(GetInvoiceRequest) returns (Invoice) {}
+          rpc MonthlyRevenue(RevenueRequest) returns (RevenueResponse) {
+            option (fpa.classification) = "EO";
+            option (fpa.ftr) = "Invoice, Customer";
+          }
+          rpc WatchInvoices(Empty) returns (stream Invoice);
+          rpc Ping(Empty) returns (Empty) {
+            option (fpa.classification) = "IGNORE";
+          }
+          rpc Reconcile(Empty) returns (Empty);
+        }
+    "#;
+
+    #[test]
+    fn should_classify_rpcs() {
+        let set = import_proto(PROTO).unwrap();
+        let proposals = set.proposals();
+        let classifications: Vec<_> = proposals
+            .iter()
+            .map(|proposal| {
+                proposal
+                    .component()
+                    .functional_classification()
+                    .abbreviation()
+            })
+            .collect();
+
+        assert_eq!(classifications, vec!["EI", "EQ", "EO", "EO", "EI"]);
+        assert_eq!(proposals[2].confidence(), Confidence::High);
+        assert_eq!(proposals[0].confidence(), Confidence::Medium);
+        assert_eq!(proposals[4].confidence(), Confidence::Low);
+        assert_eq!(
+            proposals[0].component().traceability().source_references(),
+            ["billing.v1.InvoiceService/CreateInvoice"]
+        );
+    }
+
+    #[test]
+    fn should_count_flattened_fields() {
+        let set = import_proto(PROTO).unwrap();
+        let create = set.proposals()[0].component();
+
+        assert_eq!(create.elementary_data_referenced().input(), &10);
+        assert_eq!(create.elementary_data_referenced().output(), &0);
+        assert_eq!(create.file_record().total(), 2);
+
+        let get = set.proposals()[1].component();
+
+        assert_eq!(get.elementary_data_referenced().input(), &1);
+        assert_eq!(get.elementary_data_referenced().output(), &9);
+
+        let revenue = set.proposals()[2].component();
+
+        assert_eq!(revenue.elementary_data_referenced().total(), 2);
+        assert_eq!(revenue.file_record().output, 2);
+    }
+
+    #[test]
+    fn should_draft_project() {
+        let project = draft_project_from_proto(PROTO).unwrap();
+
+        assert_eq!(project.metadata().name(), "billing.v1");
+        assert_eq!(project.basic_functional_components().len(), 5);
+
+        let error = import_proto("message A {\n  string id = 1\n}").unwrap_err();
+
+        assert_eq!(error.to_string(), "line 3: expected `;`");
+    }
+}
@@ -0,0 +1,848 @@
+use super::{ImportError, Proposal, ProposalSet};
+use crate::project::basic_functional_component::{
+    BasicFunctionalComponent, ElementaryDataReferenced, FileRegistry, FunctionalClassification,
+};
+
+/// Table read from a `CREATE TABLE` statement.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Table {
+    name: String,
+    columns: Vec<String>,
+    primary_key: Vec<String>,
+    foreign_keys: Vec<ForeignKey>,
+}
+
+/// Foreign key from some columns of a table to another table.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct ForeignKey {
+    pub columns: Vec<String>,
+    pub references: String,
+}
+
+/// Tables grouped into one logical file.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct LogicalFile {
+    pub name: String,
+    /// Tables counted as record element types, the main table first.
+    pub tables: Vec<String>,
+    /// Join tables without attributes of their own, not counted as RETs.
+    pub join_tables: Vec<String>,
+    pub data_element_types: Vec<String>,
+}
+
+impl LogicalFile {
+    /// Number of record element types.
+    pub fn record_element_types(&self) -> u32 {
+        self.tables.len() as u32
+    }
+
+    /// ILF proposal for the logical file.
+    pub fn proposal(&self) -> Proposal {
+        let mut bfc = BasicFunctionalComponent::new(
+            &self.name,
+            FunctionalClassification::InternalLogicalFile,
+        );
+
+        bfc.set_edr(ElementaryDataReferenced::new(
+            self.data_element_types.len() as u32,
+            0,
+        ))
+        .set_file_registry(FileRegistry::new(self.record_element_types(), 0));
+
+        for table in self.tables.iter().chain(&self.join_tables) {
+            bfc.add_source_reference(format!("table {}", table));
+        }
+
+        let mut proposal = Proposal::new(bfc);
+
+        for table in self.tables.iter().skip(1) {
+            proposal.add_rationale(format!("{} grouped into {} as a RET", table, self.name));
+        }
+
+        for table in &self.join_tables {
+            proposal.add_rationale(format!("{} is a join table without attributes", table));
+        }
+
+        proposal
+    }
+}
+
+impl Table {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn add_column(&mut self, column: impl Into<String>) -> &mut Self {
+        self.columns.push(column.into());
+        self
+    }
+
+    pub fn add_primary_key(&mut self, column: impl Into<String>) -> &mut Self {
+        self.primary_key.push(column.into());
+        self
+    }
+
+    pub fn add_foreign_key(
+        &mut self,
+        columns: Vec<String>,
+        references: impl Into<String>,
+    ) -> &mut Self {
+        self.foreign_keys.push(ForeignKey {
+            columns,
+            references: references.into(),
+        });
+        self
+    }
+
+    /// Get a reference to the table's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get a reference to the table's columns.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Get a reference to the table's primary key columns.
+    pub fn primary_key(&self) -> &[String] {
+        &self.primary_key
+    }
+
+    /// Get a reference to the table's foreign keys.
+    pub fn foreign_keys(&self) -> &[ForeignKey] {
+        &self.foreign_keys
+    }
+
+    fn is_foreign_key_column(&self, column: &str) -> bool {
+        self.foreign_keys
+            .iter()
+            .any(|fk| fk.columns.iter().any(|c| c.eq_ignore_ascii_case(column)))
+    }
+
+    /// Whether the table only links other tables together.
+    fn is_join_table(&self) -> bool {
+        self.foreign_keys.len() >= 2
+            && self
+                .columns
+                .iter()
+                .all(|column| self.is_foreign_key_column(column))
+    }
+
+    /// Whether the table depends on the table `fk` references, either because
+    /// the key is part of its primary key or because its name extends the parent's.
+    fn depends_on(&self, fk: &ForeignKey) -> bool {
+        let identifying = !self.primary_key.is_empty()
+            && fk
+                .columns
+                .iter()
+                .all(|c| self.primary_key.iter().any(|pk| pk.eq_ignore_ascii_case(c)));
+
+        let name = self.name.to_lowercase();
+        let parent = fk.references.to_lowercase();
+        let named = [parent.as_str(), parent.trim_end_matches('s')]
+            .iter()
+            .any(|stem| !stem.is_empty() && name.starts_with(&format!("{}_", stem)));
+
+        identifying || named
+    }
+}
+
+/// Parse the `CREATE TABLE` and `ALTER TABLE ... FOREIGN KEY` statements of a
+/// PostgreSQL or MySQL schema, ignoring every other statement.
+///
+/// Backslashes escape characters in the literals of a schema quoting names
+/// with backticks, as MySQL does, and in PostgreSQL `E'...'` literals only.
+pub fn parse_ddl(sql: &str) -> Result<Vec<Table>, ImportError> {
+    let tokens = tokenize(sql)?;
+    let mut tables: Vec<Table> = Vec::new();
+
+    for statement in tokens.split(|token| token.is_symbol(';')) {
+        if statement.is_empty() {
+            continue;
+        }
+
+        let mut parser = Parser {
+            tokens: statement,
+            position: 0,
+        };
+
+        if parser.eat_word("CREATE") {
+            parser.eat_word("OR");
+            parser.eat_word("REPLACE");
+
+            while parser.eat_any_word(&["TEMPORARY", "TEMP", "UNLOGGED", "GLOBAL", "LOCAL"]) {}
+
+            if !parser.eat_word("TABLE") {
+                continue;
+            }
+
+            parser.eat_if_not_exists();
+
+            let name = parser.qualified_name()?;
+
+            if !parser.peek_symbol('(') {
+                continue;
+            }
+
+            let table = parser.table_body(name)?;
+
+            tables.push(table);
+        } else if parser.eat_word("ALTER") && parser.eat_word("TABLE") {
+            parser.eat_word("IF");
+            parser.eat_word("EXISTS");
+            parser.eat_word("ONLY");
+
+            let name = parser.qualified_name()?;
+
+            while parser.position < statement.len() {
+                if parser.eat_word("FOREIGN") && parser.eat_word("KEY") {
+                    let columns = parser.column_list()?;
+                    parser.expect_word("REFERENCES")?;
+                    let references = parser.qualified_name()?;
+
+                    if let Some(table) = tables
+                        .iter_mut()
+                        .find(|table| table.name.eq_ignore_ascii_case(&name))
+                    {
+                        table.add_foreign_key(columns, references);
+                    }
+                } else {
+                    parser.position += 1;
+                }
+            }
+        }
+    }
+
+    Ok(tables)
+}
+
+/// Group tables into logical files. A table joins the logical file of the
+/// table it depends on; join tables without attributes are attached to the
+/// first table they reference.
+pub fn group_tables(tables: &[Table]) -> Vec<LogicalFile> {
+    let find = |name: &str| {
+        tables
+            .iter()
+            .position(|table| table.name.eq_ignore_ascii_case(name))
+    };
+
+    let parent = |index: usize| {
+        let table = &tables[index];
+
+        table
+            .foreign_keys
+            .iter()
+            .filter(|fk| table.is_join_table() || table.depends_on(fk))
+            .filter_map(|fk| find(&fk.references))
+            .find(|&parent| parent != index)
+    };
+
+    // Tables depending on each other form a cycle, rooted at the table of
+    // the cycle declared first.
+    let root = |index: usize| {
+        let mut visited = vec![index];
+        let mut current = index;
+
+        while let Some(next) = parent(current) {
+            if let Some(start) = visited.iter().position(|&v| v == next) {
+                return visited[start..].iter().copied().min().unwrap_or(next);
+            }
+
+            visited.push(next);
+            current = next;
+        }
+
+        current
+    };
+
+    let roots: Vec<usize> = (0..tables.len()).map(root).collect();
+    let mut files = Vec::new();
+
+    for (index, table) in tables.iter().enumerate() {
+        if roots[index] != index || table.is_join_table() {
+            continue;
+        }
+
+        let members: Vec<&Table> = (0..tables.len())
+            .filter(|&i| roots[i] == index)
+            .map(|i| &tables[i])
+            .collect();
+
+        let mut file = LogicalFile {
+            name: table.name.clone(),
+            tables: Vec::new(),
+            join_tables: Vec::new(),
+            data_element_types: Vec::new(),
+        };
+
+        for member in members {
+            if member.is_join_table() {
+                file.join_tables.push(member.name.clone());
+                continue;
+            }
+
+            file.tables.push(member.name.clone());
+
+            for column in &member.columns {
+                let links_inside = member.foreign_keys.iter().any(|fk| {
+                    fk.columns.iter().any(|c| c.eq_ignore_ascii_case(column))
+                        && find(&fk.references).map(|i| roots[i]) == Some(index)
+                });
+
+                if member.name != table.name && links_inside {
+                    continue;
+                }
+
+                if !file
+                    .data_element_types
+                    .iter()
+                    .any(|det| det.eq_ignore_ascii_case(column))
+                {
+                    file.data_element_types.push(column.clone());
+                }
+            }
+        }
+
+        files.push(file);
+    }
+
+    files
+}
+
+/// Propose one ILF per logical file found in a DDL schema.
+pub fn import_ddl(sql: &str) -> Result<ProposalSet, ImportError> {
+    let mut set = ProposalSet::new();
+
+    for file in group_tables(&parse_ddl(sql)?) {
+        set.add(file.proposal());
+    }
+
+    Ok(set)
+}
+
+#[derive(PartialEq, Debug, Clone)]
+enum TokenKind {
+    Word(String),
+    Quoted(String),
+    Literal,
+    Symbol(char),
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    line: usize,
+}
+
+impl Token {
+    fn is_symbol(&self, symbol: char) -> bool {
+        self.kind == TokenKind::Symbol(symbol)
+    }
+
+    fn is_word(&self, word: &str) -> bool {
+        matches!(&self.kind, TokenKind::Word(w) if w.eq_ignore_ascii_case(word))
+    }
+
+    fn identifier(&self) -> Option<&str> {
+        match &self.kind {
+            TokenKind::Word(w) | TokenKind::Quoted(w) => Some(w),
+            _ => None,
+        }
+    }
+}
+
+fn tokenize(sql: &str) -> Result<Vec<Token>, ImportError> {
+    let chars: Vec<char> = sql.chars().collect();
+    // PostgreSQL rejects backtick-quoted names, so they mark a MySQL schema.
+    let mysql = sql.contains('`');
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = line;
+
+        if c == '\n' {
+            line += 1;
+            i += 1;
+        } else if c.is_whitespace() {
+            i += 1;
+        } else if c == '#' || (c == '-' && chars.get(i + 1) == Some(&'-')) {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+
+            loop {
+                match chars.get(i) {
+                    None => return Err(ImportError::new(start, "unterminated comment")),
+                    Some('*') if chars.get(i + 1) == Some(&'/') => break,
+                    Some('\n') => line += 1,
+                    _ => {}
+                }
+                i += 1;
+            }
+
+            i += 2;
+        } else if let Some(close) = match c {
+            '\'' => Some('\''),
+            '"' => Some('"'),
+            '`' => Some('`'),
+            'E' | 'e' if chars.get(i + 1) == Some(&'\'') => Some('\''),
+            _ => None,
+        } {
+            let escape_string = c != close;
+            let backslashes = escape_string || (mysql && close == '\'');
+            let mut text = String::new();
+
+            i += if escape_string { 2 } else { 1 };
+
+            loop {
+                match chars.get(i) {
+                    None => return Err(ImportError::new(start, "unterminated quote")),
+                    Some(&q) if q == close => {
+                        if chars.get(i + 1) == Some(&close) {
+                            text.push(close);
+                            i += 2;
+                            continue;
+                        }
+                        break;
+                    }
+                    Some('\\') if backslashes => {
+                        if let Some(&escaped) = chars.get(i + 1) {
+                            if escaped == '\n' {
+                                line += 1;
+                            }
+                            text.push(escaped);
+                            i += 1;
+                        }
+                    }
+                    Some(&other) => {
+                        if other == '\n' {
+                            line += 1;
+                        }
+                        text.push(other);
+                    }
+                }
+                i += 1;
+            }
+
+            i += 1;
+
+            let kind = if close == '\'' {
+                TokenKind::Literal
+            } else {
+                TokenKind::Quoted(text)
+            };
+
+            tokens.push(Token { kind, line: start });
+        } else if c.is_alphanumeric() || c == '_' || c == '$' {
+            let mut word = String::new();
+
+            while i < chars.len()
+                && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '$')
+            {
+                word.push(chars[i]);
+                i += 1;
+            }
+
+            tokens.push(Token {
+                kind: TokenKind::Word(word),
+                line: start,
+            });
+        } else {
+            tokens.push(Token {
+                kind: TokenKind::Symbol(c),
+                line: start,
+            });
+            i += 1;
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn line(&self) -> usize {
+        self.peek()
+            .or_else(|| self.tokens.last())
+            .map_or(1, |token| token.line)
+    }
+
+    fn peek_symbol(&self, symbol: char) -> bool {
+        self.peek().is_some_and(|token| token.is_symbol(symbol))
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        let found = self.peek().is_some_and(|token| token.is_word(word));
+
+        if found {
+            self.position += 1;
+        }
+
+        found
+    }
+
+    fn eat_any_word(&mut self, words: &[&str]) -> bool {
+        words.iter().any(|word| self.eat_word(word))
+    }
+
+    fn expect_word(&mut self, word: &str) -> Result<(), ImportError> {
+        if self.eat_word(word) {
+            Ok(())
+        } else {
+            Err(ImportError::new(self.line(), format!("expected {}", word)))
+        }
+    }
+
+    fn eat_if_not_exists(&mut self) {
+        let start = self.position;
+
+        if !(self.eat_word("IF") && self.eat_word("NOT") && self.eat_word("EXISTS")) {
+            self.position = start;
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, ImportError> {
+        match self.peek().and_then(Token::identifier) {
+            Some(name) => {
+                self.position += 1;
+                Ok(name.to_string())
+            }
+            None => Err(ImportError::new(self.line(), "expected a name")),
+        }
+    }
+
+    /// Name possibly qualified by a schema, of which only the last part is kept.
+    fn qualified_name(&mut self) -> Result<String, ImportError> {
+        let mut name = self.identifier()?;
+
+        while self.peek_symbol('.') {
+            self.position += 1;
+            name = self.identifier()?;
+        }
+
+        Ok(name)
+    }
+
+    fn column_list(&mut self) -> Result<Vec<String>, ImportError> {
+        let mut columns = Vec::new();
+
+        if !self.peek_symbol('(') {
+            return Err(ImportError::new(self.line(), "expected a column list"));
+        }
+
+        self.position += 1;
+
+        loop {
+            columns.push(self.identifier()?);
+
+            // Skips MySQL prefix lengths and sort orders.
+            while !self.peek_symbol(',') && !self.peek_symbol(')') {
+                if self.peek().is_none() {
+                    return Err(ImportError::new(self.line(), "unclosed column list"));
+                }
+                self.skip_item()?;
+            }
+
+            self.position += 1;
+
+            if self.tokens[self.position - 1].is_symbol(')') {
+                return Ok(columns);
+            }
+        }
+    }
+
+    /// Skip one token, or a whole parenthesised group.
+    fn skip_item(&mut self) -> Result<(), ImportError> {
+        let line = self.line();
+        let mut depth = 0;
+
+        loop {
+            let token = self
+                .peek()
+                .ok_or_else(|| ImportError::new(line, "unbalanced parentheses"))?;
+
+            self.position += 1;
+
+            if token.is_symbol('(') {
+                depth += 1;
+            } else if token.is_symbol(')') {
+                depth -= 1;
+            }
+
+            if depth <= 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    fn table_body(&mut self, name: String) -> Result<Table, ImportError> {
+        let mut table = Table::new(name);
+
+        self.position += 1;
+
+        loop {
+            self.table_element(&mut table)?;
+
+            match self.peek() {
+                Some(token) if token.is_symbol(',') => self.position += 1,
+                Some(token) if token.is_symbol(')') => {
+                    self.position += 1;
+                    return Ok(table);
+                }
+                _ => {
+                    return Err(ImportError::new(
+                        self.line(),
+                        format!("unclosed definition of table {}", table.name),
+                    ))
+                }
+            }
+        }
+    }
+
+    fn table_element(&mut self, table: &mut Table) -> Result<(), ImportError> {
+        if self.eat_word("CONSTRAINT") {
+            self.identifier()?;
+        }
+
+        if self.eat_word("PRIMARY") {
+            self.expect_word("KEY")?;
+            table.primary_key.extend(self.column_list()?);
+        } else if self.eat_word("FOREIGN") {
+            self.expect_word("KEY")?;
+            let columns = self.column_list()?;
+            self.expect_word("REFERENCES")?;
+            let references = self.qualified_name()?;
+            table.add_foreign_key(columns, references);
+        } else if self.eat_any_word(&[
+            "UNIQUE", "KEY", "INDEX", "CHECK", "FULLTEXT", "SPATIAL", "EXCLUDE", "LIKE", "PERIOD",
+        ]) {
+        } else {
+            let column = self.identifier()?;
+
+            while self.peek().is_some() && !self.peek_symbol(',') && !self.peek_symbol(')') {
+                if self.eat_word("PRIMARY") {
+                    self.expect_word("KEY")?;
+                    table.add_primary_key(column.clone());
+                } else if self.eat_word("REFERENCES") {
+                    let references = self.qualified_name()?;
+                    table.add_foreign_key(vec![column.clone()], references);
+                } else {
+                    self.skip_item()?;
+                }
+            }
+
+            table.add_column(column);
+            return Ok(());
+        }
+
+        while self.peek().is_some() && !self.peek_symbol(',') && !self.peek_symbol(')') {
+            self.skip_item()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"
+        -- Sales schema
+        CREATE TABLE IF NOT EXISTS public.customers (
+            id SERIAL PRIMARY KEY,
+            name VARCHAR(120) NOT NULL,
+            email VARCHAR(255) UNIQUE,
+            created_at TIMESTAMP DEFAULT now()
+        );
+
+        CREATE TABLE customer_addresses (
+            id SERIAL PRIMARY KEY,
+            customer_id INTEGER NOT NULL REFERENCES customers (id),
+            street TEXT,
+            city TEXT
+        );
+
+        CREATE TABLE `orders` (
+            `id` INT NOT NULL AUTO_INCREMENT,
+            `customer_id` INT NOT NULL,
+            `total` DECIMAL(10, 2),
+            PRIMARY KEY (`id`),
+            KEY `idx_customer` (`customer_id`),
+            CONSTRAINT `fk_customer` FOREIGN KEY (`customer_id`) REFERENCES `customers` (`id`)
+        ) ENGINE=InnoDB;
+
+        CREATE TABLE order_lines (
+            order_id INT NOT NULL,
+            line_number INT NOT NULL,
+            product_id INT NOT NULL,
+            quantity INT,
+            PRIMARY KEY (order_id, line_number)
+        );
+
+        /* products and their tags */
+        CREATE TABLE products (id INT PRIMARY KEY, description TEXT);
+        CREATE TABLE product_tags (product_id INT, tag_id INT);
+        CREATE TABLE tags (id INT PRIMARY KEY, label TEXT);
+
+        ALTER TABLE IF EXISTS ONLY order_lines
+            ADD CONSTRAINT fk_order FOREIGN KEY (order_id) REFERENCES orders (id);
+        ALTER TABLE order_lines ADD FOREIGN KEY (product_id) REFERENCES products (id);
+        ALTER TABLE product_tags ADD FOREIGN KEY (product_id) REFERENCES products (id);
+        ALTER TABLE product_tags ADD FOREIGN KEY (tag_id) REFERENCES tags (id);
+
+        CREATE INDEX idx_email ON customers (email);
+    "#;
+
+    #[test]
+    fn should_parse_tables() {
+        let tables = parse_ddl(SCHEMA).unwrap();
+
+        assert_eq!(tables.len(), 7);
+        assert_eq!(tables[0].name(), "customers");
+        assert_eq!(tables[0].columns(), ["id", "name", "email", "created_at"]);
+        assert_eq!(tables[2].primary_key(), ["id"]);
+        assert_eq!(tables[2].columns(), ["id", "customer_id", "total"]);
+        assert_eq!(tables[2].foreign_keys()[0].references, "customers");
+        assert_eq!(tables[3].foreign_keys().len(), 2);
+    }
+
+    #[test]
+    fn should_group_tables_into_logical_files() {
+        let files = group_tables(&parse_ddl(SCHEMA).unwrap());
+        let names: Vec<_> = files.iter().map(|file| file.name.as_str()).collect();
+
+        assert_eq!(names, vec!["customers", "orders", "products", "tags"]);
+
+        assert_eq!(files[0].tables, ["customers", "customer_addresses"]);
+        assert_eq!(
+            files[0].data_element_types,
+            ["id", "name", "email", "created_at", "street", "city"]
+        );
+
+        assert_eq!(files[1].tables, ["orders", "order_lines"]);
+        assert_eq!(files[1].record_element_types(), 2);
+        assert_eq!(
+            files[1].data_element_types,
+            [
+                "id",
+                "customer_id",
+                "total",
+                "line_number",
+                "product_id",
+                "quantity"
+            ]
+        );
+
+        assert_eq!(files[2].join_tables, ["product_tags"]);
+        assert_eq!(files[2].record_element_types(), 1);
+    }
+
+    #[test]
+    fn should_propose_ilfs() {
+        let set = import_ddl(SCHEMA).unwrap();
+        let orders = set.proposals()[1].component();
+
+        assert_eq!(orders.name(), "orders");
+        assert_eq!(
+            orders.functional_classification(),
+            &FunctionalClassification::InternalLogicalFile
+        );
+        assert_eq!(orders.elementary_data_referenced().total(), 6);
+        assert_eq!(orders.file_record().total(), 2);
+        assert_eq!(
+            orders.traceability().source_references(),
+            ["table orders", "table order_lines"]
+        );
+        assert_eq!(
+            set.proposals()[1].rationale(),
+            ["order_lines grouped into orders as a RET"]
+        );
+    }
+
+    #[test]
+    fn should_read_mysql_escapes() {
+        let tables = parse_ddl(
+            "CREATE TABLE `customers` (\n  `id` INT PRIMARY KEY,\n  \
+             `name` TEXT COMMENT 'customer\\'s name'\n);",
+        )
+        .unwrap();
+
+        assert_eq!(tables[0].columns(), ["id", "name"]);
+    }
+
+    #[test]
+    fn should_read_postgresql_literals_and_arrays() {
+        let tables = parse_ddl(
+            "CREATE TABLE documents (\n  id INT PRIMARY KEY,\n  \
+             folder TEXT DEFAULT 'C:\\',\n  note TEXT DEFAULT E'it\\'s',\n  \
+             tags TEXT[]\n);",
+        )
+        .unwrap();
+
+        assert_eq!(tables[0].columns(), ["id", "folder", "note", "tags"]);
+    }
+
+    #[test]
+    fn should_propose_empty_logical_files() {
+        let file = LogicalFile {
+            name: "audit".to_string(),
+            tables: Vec::new(),
+            join_tables: Vec::new(),
+            data_element_types: Vec::new(),
+        };
+        let proposal = file.proposal();
+
+        assert_eq!(proposal.component().file_record().total(), 0);
+        assert!(proposal.rationale().is_empty());
+    }
+
+    #[test]
+    fn should_group_tables_referencing_each_other() {
+        let files = group_tables(
+            &parse_ddl(
+                "CREATE TABLE accounts (id INT PRIMARY KEY REFERENCES profiles (id), \
+                 balance INT);\n\
+                 CREATE TABLE profiles (id INT PRIMARY KEY REFERENCES accounts (id), \
+                 bio TEXT);",
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].name, "accounts");
+        assert_eq!(files[0].tables, ["accounts", "profiles"]);
+    }
+
+    #[test]
+    fn should_report_malformed_ddl() {
+        let error = parse_ddl("CREATE TABLE a (\n  id INT,\n  name TEXT").unwrap_err();
+
+        assert_eq!(error.line(), 3);
+        assert_eq!(error.message(), "unclosed definition of table a");
+        assert_eq!(
+            parse_ddl("CREATE TABLE 'a").unwrap_err().to_string(),
+            "line 1: unterminated quote"
+        );
+    }
+}
@@ -1,3 +1,43 @@
+mod dsl;
+mod import;
+#[cfg(feature = "lsp")]
+mod lsp;
+mod portfolio;
 mod project;
+#[cfg(feature = "python")]
+mod python;
+mod repository;
+#[cfg(feature = "server")]
+mod server;
+#[cfg(feature = "wasm")]
+mod wasm;
 
-pub use project::{basic_functional_component::*, Project};
+pub use dsl::{project_from_dsl, project_to_dsl, Diagnostic};
+#[cfg(feature = "macros")]
+pub use fpa_macros::{data_function, transaction};
+#[cfg(feature = "openapi")]
+pub use import::openapi::{draft_project_from_openapi, import_openapi};
+pub use import::{
+    annotations::{collect_annotations, project_from_annotations},
+    gherkin::{import_gherkin, GherkinImport, UncountedScenario},
+    graphql::import_graphql,
+    markdown::{import_markdown, write_markdown_appendix},
+    orm::{import_rust_crate, scan_rust_crate},
+    proto::{draft_project_from_proto, import_proto},
+    sql::*,
+    Confidence, ImportError, Proposal, ProposalSet, Review,
+};
+#[cfg(feature = "lsp")]
+pub use lsp::LanguageServer;
+pub use portfolio::*;
+pub use project::{
+    adjustment_factors::*, audit::*, basic_functional_component::*, diff::*, early_quick::*,
+    general_system_characteristics::*, grouping::*, metadata::*, planning::*, progress::*,
+    report::*, simple_function_points::*, summary::*, traceability_matrix::*, value_adjustment::*,
+    weighting_factors::*, Project,
+};
+pub use repository::{calibration::*, HistoricalRecord, Repository};
+#[cfg(feature = "server")]
+pub use server::CountingService;
+#[cfg(feature = "wasm")]
+pub use wasm::WasmProject;
@@ -0,0 +1,536 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+use serde_json::{json, Value};
+
+use crate::dsl::{analyse, Analysis};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+
+const SEVERITY_ERROR: u8 = 1;
+const SEVERITY_WARNING: u8 = 2;
+
+/// Language server for counts written in the compact text format, speaking
+/// JSON-RPC over any reader and writer, usually stdin and stdout.
+///
+/// It publishes diagnostics, including FTRs naming undeclared data
+/// functions, shows the complexity and weight of a component on hover,
+/// completes data function names and adds a code lens with the running
+/// UFP total to every component. Columns are sent in UTF-16 code units, the
+/// LSP default, or in characters when the client offers `utf-32`.
+#[derive(Debug, Default)]
+pub struct LanguageServer {
+    documents: HashMap<String, String>,
+    shutdown: bool,
+    utf32: bool,
+}
+
+impl LanguageServer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serve messages until the client sends `exit` or closes the input.
+    pub fn run<R: BufRead, W: Write>(&mut self, mut reader: R, mut writer: W) -> io::Result<()> {
+        while let Some(body) = read_message(&mut reader)? {
+            let message = match serde_json::from_slice::<Value>(&body) {
+                Ok(message) => message,
+                Err(e) => {
+                    let error = error_response(Value::Null, PARSE_ERROR, &e.to_string());
+
+                    write_message(&mut writer, &error)?;
+                    continue;
+                }
+            };
+
+            if message["method"] == "exit" {
+                break;
+            }
+
+            for reply in self.handle(&message) {
+                write_message(&mut writer, &reply)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handle a request or notification, returning the messages to send back.
+    pub fn handle(&mut self, message: &Value) -> Vec<Value> {
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+        let id = message.get("id").cloned();
+
+        if self.shutdown && id.is_some() {
+            return vec![error_response(
+                id.unwrap_or_default(),
+                INVALID_REQUEST,
+                "server is shutting down",
+            )];
+        }
+
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+
+        let result = match method {
+            "initialize" => {
+                self.utf32 = params["capabilities"]["general"]["positionEncodings"]
+                    .as_array()
+                    .is_some_and(|encodings| encodings.iter().any(|e| e == "utf-32"));
+
+                let mut capabilities = json!({
+                    "textDocumentSync": 1,
+                    "hoverProvider": true,
+                    "completionProvider": { "triggerCharacters": ["=", ","] },
+                    "codeLensProvider": { "resolveProvider": false },
+                });
+
+                if self.utf32 {
+                    capabilities["positionEncoding"] = json!("utf-32");
+                }
+
+                json!({
+                    "capabilities": capabilities,
+                    "serverInfo": { "name": "fpa-lsp", "version": env!("CARGO_PKG_VERSION") },
+                })
+            }
+            "shutdown" => {
+                self.shutdown = true;
+                Value::Null
+            }
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"].as_str().unwrap_or_default();
+
+                return self.open(uri, text.to_string());
+            }
+            "textDocument/didChange" => {
+                let text = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                    .unwrap_or_default();
+
+                return self.open(uri, text.to_string());
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(uri);
+
+                return vec![publish_diagnostics(uri, Vec::new())];
+            }
+            "textDocument/hover" => self.hover(uri, params),
+            "textDocument/completion" => self.completion(uri),
+            "textDocument/codeLens" => self.code_lens(uri),
+            _ => {
+                return match id {
+                    Some(id) => vec![error_response(
+                        id,
+                        METHOD_NOT_FOUND,
+                        &format!("unsupported method `{}`", method),
+                    )],
+                    None => Vec::new(),
+                }
+            }
+        };
+
+        match id {
+            Some(id) => vec![json!({ "jsonrpc": "2.0", "id": id, "result": result })],
+            None => Vec::new(),
+        }
+    }
+
+    fn open(&mut self, uri: &str, text: String) -> Vec<Value> {
+        let analysis = analyse(&text);
+        let mut diagnostics: Vec<Value> = analysis
+            .diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let line = text.lines().nth(diagnostic.line() - 1).unwrap_or_default();
+
+                diagnostic_json(
+                    diagnostic.line() - 1,
+                    self.character(line, diagnostic.column() - 1),
+                    self.character(line, line.chars().count().max(diagnostic.column())),
+                    SEVERITY_ERROR,
+                    diagnostic.message(),
+                )
+            })
+            .collect();
+
+        let data_functions = data_function_names(&analysis);
+
+        for component in &analysis.components {
+            let line = text.lines().nth(component.line - 1).unwrap_or_default();
+
+            for (column, name) in &component.references {
+                if !data_functions.contains(&name.as_str()) {
+                    diagnostics.push(diagnostic_json(
+                        component.line - 1,
+                        self.character(line, column - 1),
+                        self.character(line, column - 1 + name.chars().count()),
+                        SEVERITY_WARNING,
+                        &format!("`{}` is not a declared data function", name),
+                    ));
+                }
+            }
+        }
+
+        self.documents.insert(uri.to_string(), text);
+
+        vec![publish_diagnostics(uri, diagnostics)]
+    }
+
+    /// Convert a column counted in characters to the negotiated encoding.
+    fn character(&self, line: &str, column: usize) -> usize {
+        if self.utf32 {
+            return column;
+        }
+
+        let units: usize = line.chars().take(column).map(char::len_utf16).sum();
+
+        units + column.saturating_sub(line.chars().count())
+    }
+
+    fn analysis(&self, uri: &str) -> Option<Analysis> {
+        self.documents.get(uri).map(|text| analyse(text))
+    }
+
+    fn hover(&self, uri: &str, params: &Value) -> Value {
+        let line = params["position"]["line"].as_u64().unwrap_or_default() as usize + 1;
+        let analysis = match self.analysis(uri) {
+            Some(analysis) => analysis,
+            None => return Value::Null,
+        };
+
+        let component = match analysis.components.iter().find(|c| c.line == line) {
+            Some(component) => component,
+            None => return Value::Null,
+        };
+
+        let project = &analysis.project;
+        let bfc = &project.basic_functional_components()[component.index];
+        let classification = bfc.functional_classification();
+
+        json!({
+            "contents": {
+                "kind": "markdown",
+                "value": format!(
+                    "**{}** {}\n\nDET {}, {} {}: {} complexity, weight {}",
+                    classification.abbreviation(),
+                    bfc.name(),
+                    bfc.elementary_data_referenced().total(),
+                    if classification.is_transactional() { "FTR" } else { "RET" },
+                    bfc.file_record().total(),
+                    bfc.complexity(),
+                    project.weighting_factors().weight(bfc)
+                ),
+            },
+        })
+    }
+
+    fn completion(&self, uri: &str) -> Value {
+        let analysis = match self.analysis(uri) {
+            Some(analysis) => analysis,
+            None => return json!([]),
+        };
+
+        analysis
+            .project
+            .basic_functional_components()
+            .iter()
+            .filter(|bfc| bfc.functional_classification().is_data_function())
+            .map(|bfc| {
+                json!({
+                    "label": bfc.name(),
+                    "kind": 22,
+                    "detail": bfc.functional_classification().abbreviation(),
+                })
+            })
+            .collect()
+    }
+
+    fn code_lens(&self, uri: &str) -> Value {
+        let analysis = match self.analysis(uri) {
+            Some(analysis) => analysis,
+            None => return json!([]),
+        };
+
+        let project = &analysis.project;
+        let mut running = 0;
+
+        analysis
+            .components
+            .iter()
+            .map(|component| {
+                let bfc = &project.basic_functional_components()[component.index];
+                let weight = project.weighting_factors().weight(bfc);
+
+                running += weight;
+
+                json!({
+                    "range": range(component.line - 1, 0, 0),
+                    "command": {
+                        "title": format!("{} FP, running UFP {}", weight, running),
+                        "command": "",
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+fn data_function_names(analysis: &Analysis) -> Vec<&str> {
+    analysis
+        .project
+        .basic_functional_components()
+        .iter()
+        .filter(|bfc| bfc.functional_classification().is_data_function())
+        .map(|bfc| bfc.name().as_str())
+        .collect()
+}
+
+fn range(line: usize, start: usize, end: usize) -> Value {
+    json!({
+        "start": { "line": line, "character": start },
+        "end": { "line": line, "character": end },
+    })
+}
+
+fn diagnostic_json(line: usize, start: usize, end: usize, severity: u8, message: &str) -> Value {
+    json!({
+        "range": range(line, start, end),
+        "severity": severity,
+        "source": "fpa",
+        "message": message,
+    })
+}
+
+fn publish_diagnostics(uri: &str, diagnostics: Vec<Value>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": uri, "diagnostics": diagnostics },
+    })
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+/// Read the body of the next `Content-Length` framed message.
+fn read_message<R: BufRead>(reader: &mut R) -> io::Result<Option<Vec<u8>>> {
+    let mut length = None;
+
+    loop {
+        let mut header = String::new();
+
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+
+        let header = header.trim_end();
+
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("Content-Length") {
+                length = Some(value.trim().parse::<usize>().map_err(|_| {
+                    io::Error::new(io::ErrorKind::InvalidData, "invalid Content-Length")
+                })?);
+            }
+        }
+    }
+
+    let length = length
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing Content-Length"))?;
+    let mut body = vec![0; length];
+
+    reader.read_exact(&mut body)?;
+
+    Ok(Some(body))
+}
+
+fn write_message<W: Write>(writer: &mut W, message: &Value) -> io::Result<()> {
+    let body = message.to_string();
+
+    write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COUNT: &str = "@weights EI=3,4,6 EO=4,5,7 EQ=3,4,6 ILF=7,10,15
+ILF Customer det=20 ret=2
+EI \"Create customer\" det=5 ftr=Customer,Address
+EQ \"List customers\" det=3 ftr=Customer
+";
+
+    fn frame(messages: &[Value]) -> Vec<u8> {
+        let mut input = Vec::new();
+
+        for message in messages {
+            write_message(&mut input, message).unwrap();
+        }
+
+        input
+    }
+
+    fn replies(output: &[u8]) -> Vec<Value> {
+        let mut reader = output;
+        let mut replies = Vec::new();
+
+        while let Some(body) = read_message(&mut reader).unwrap() {
+            replies.push(serde_json::from_slice(&body).unwrap());
+        }
+
+        replies
+    }
+
+    #[test]
+    fn should_serve_over_stdio() {
+        let uri = "file:///billing.fpa";
+        let input = frame(&[
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+            json!({ "jsonrpc": "2.0", "method": "initialized", "params": {} }),
+            json!({
+                "jsonrpc": "2.0",
+                "method": "textDocument/didOpen",
+                "params": { "textDocument": { "uri": uri, "text": COUNT } },
+            }),
+            json!({
+                "jsonrpc": "2.0",
+                "id": 2,
+                "method": "textDocument/hover",
+                "params": { "textDocument": { "uri": uri }, "position": { "line": 2, "character": 3 } },
+            }),
+            json!({
+                "jsonrpc": "2.0",
+                "id": 3,
+                "method": "textDocument/completion",
+                "params": { "textDocument": { "uri": uri }, "position": { "line": 3, "character": 36 } },
+            }),
+            json!({
+                "jsonrpc": "2.0",
+                "id": 4,
+                "method": "textDocument/codeLens",
+                "params": { "textDocument": { "uri": uri } },
+            }),
+            json!({ "jsonrpc": "2.0", "id": 5, "method": "shutdown" }),
+            json!({ "jsonrpc": "2.0", "method": "exit" }),
+        ]);
+        let mut output = Vec::new();
+
+        LanguageServer::new().run(&input[..], &mut output).unwrap();
+
+        let replies = replies(&output);
+
+        assert_eq!(replies.len(), 6);
+        assert_eq!(replies[0]["result"]["capabilities"]["hoverProvider"], true);
+
+        let diagnostics = &replies[1]["params"]["diagnostics"];
+
+        assert_eq!(diagnostics.as_array().unwrap().len(), 1);
+        assert_eq!(
+            diagnostics[0]["message"],
+            "`Address` is not a declared data function"
+        );
+        assert_eq!(diagnostics[0]["range"], range(2, 40, 47));
+
+        assert_eq!(
+            replies[2]["result"]["contents"]["value"],
+            "**EI** Create customer\n\nDET 5, FTR 2: Middle complexity, weight 4"
+        );
+        assert_eq!(replies[3]["result"][0]["label"], "Customer");
+
+        let titles: Vec<_> = replies[4]["result"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|lens| lens["command"]["title"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(
+            titles,
+            vec![
+                "10 FP, running UFP 10",
+                "4 FP, running UFP 14",
+                "3 FP, running UFP 17"
+            ]
+        );
+        assert_eq!(replies[5]["result"], Value::Null);
+    }
+
+    #[test]
+    fn should_report_parse_errors() {
+        let mut server = LanguageServer::new();
+        let replies = server.handle(&json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": {
+                "textDocument": { "uri": "file:///a.fpa", "text": "@gsc performance=9\nXI Foo\n" },
+            },
+        }));
+        let diagnostics = replies[0]["params"]["diagnostics"].as_array().unwrap();
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(
+            diagnostics[0]["message"],
+            "degree of influence must be at most 5"
+        );
+        assert_eq!(diagnostics[1]["range"]["start"]["line"], 1);
+        assert_eq!(diagnostics[1]["severity"], SEVERITY_ERROR);
+
+        let unknown = server.handle(&json!({ "jsonrpc": "2.0", "id": 7, "method": "foo" }));
+
+        assert_eq!(unknown[0]["error"]["code"], METHOD_NOT_FOUND);
+    }
+
+    #[test]
+    fn should_count_columns_in_utf16_unless_the_client_offers_utf32() {
+        let open = json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": {
+                "textDocument": { "uri": "file:///a.fpa", "text": "EQ \"\u{1F4CB} list\" det=3 ftr=Missing\n" },
+            },
+        });
+
+        let mut server = LanguageServer::new();
+        let initialize = server
+            .handle(&json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }));
+
+        assert_eq!(
+            initialize[0]["result"]["capabilities"].get("positionEncoding"),
+            None
+        );
+        assert_eq!(
+            server.handle(&open)[0]["params"]["diagnostics"][0]["range"],
+            range(0, 23, 30)
+        );
+
+        let mut server = LanguageServer::new();
+        let initialize = server.handle(&json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": { "capabilities": { "general": { "positionEncodings": ["utf-16", "utf-32"] } } },
+        }));
+
+        assert_eq!(
+            initialize[0]["result"]["capabilities"]["positionEncoding"],
+            "utf-32"
+        );
+        assert_eq!(
+            server.handle(&open)[0]["params"]["diagnostics"][0]["range"],
+            range(0, 22, 29)
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use crate::project::basic_functional_component::FunctionalClassification;
+use crate::project::summary::Summary;
+use crate::project::Project;
+
+/// Set of application baselines managed together.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone)]
+pub struct Portfolio {
+    applications: Vec<Project>,
+}
+
+/// Size of one application of the portfolio.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct ApplicationSize {
+    pub name: String,
+    pub summary: Summary,
+    pub function_points_not_adjusted: f32,
+    pub adjusted_function_points: f32,
+}
+
+/// Data function found in more than one application.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct SharedDataFunction {
+    pub name: String,
+    /// Applications counting it as an ILF.
+    pub maintained_by: Vec<String>,
+    /// Applications counting it as an EIF.
+    pub referenced_by: Vec<String>,
+}
+
+impl SharedDataFunction {
+    /// Whether more than one application claims to maintain the data function.
+    pub fn is_conflicting(&self) -> bool {
+        self.maintained_by.len() > 1
+    }
+}
+
+impl Portfolio {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an application baseline, named after its metadata.
+    pub fn add_application(&mut self, project: Project) -> &mut Self {
+        self.applications.push(project);
+        self
+    }
+
+    /// Get a reference to the portfolio's applications.
+    pub fn applications(&self) -> &[Project] {
+        &self.applications
+    }
+
+    /// Size of each application.
+    pub fn application_sizes(&self) -> Vec<ApplicationSize> {
+        self.applications
+            .iter()
+            .map(|project| ApplicationSize {
+                name: project.metadata().name().to_string(),
+                summary: project.summary().clone(),
+                function_points_not_adjusted: project.total_function_point_not_adjusted(),
+                adjusted_function_points: project.final_adjusted_function_points(),
+            })
+            .collect()
+    }
+
+    /// Summary of all applications together.
+    pub fn total_summary(&self) -> Summary {
+        let mut summary = Summary::default();
+
+        for project in &self.applications {
+            summary += project.summary();
+        }
+
+        summary
+    }
+
+    /// Total unadjusted size of the portfolio.
+    pub fn total_function_point_not_adjusted(&self) -> f32 {
+        self.applications
+            .iter()
+            .map(Project::total_function_point_not_adjusted)
+            .sum()
+    }
+
+    /// Total adjusted size of the portfolio.
+    pub fn total_adjusted_function_points(&self) -> f32 {
+        self.applications
+            .iter()
+            .map(Project::final_adjusted_function_points)
+            .sum()
+    }
+
+    /// Data functions counted by more than one application, matched by name
+    /// regardless of case and surrounding whitespace.
+    pub fn shared_data_functions(&self) -> Vec<SharedDataFunction> {
+        let mut shared: Vec<(String, SharedDataFunction)> = Vec::new();
+
+        for project in &self.applications {
+            let application = project.metadata().name();
+
+            for bfc in project.basic_functional_components() {
+                let key = bfc.name().trim().to_lowercase();
+
+                let position = match shared.iter().position(|(k, _)| *k == key) {
+                    Some(position) => position,
+                    None => {
+                        shared.push((
+                            key,
+                            SharedDataFunction {
+                                name: bfc.name().trim().to_string(),
+                                maintained_by: Vec::new(),
+                                referenced_by: Vec::new(),
+                            },
+                        ));
+                        shared.len() - 1
+                    }
+                };
+
+                let applications = match bfc.functional_classification() {
+                    FunctionalClassification::InternalLogicalFile => {
+                        &mut shared[position].1.maintained_by
+                    }
+                    FunctionalClassification::ExternalInterfaceFile => {
+                        &mut shared[position].1.referenced_by
+                    }
+                    _ => continue,
+                };
+
+                if !applications.iter().any(|a| a == application) {
+                    applications.push(application.to_string());
+                }
+            }
+        }
+
+        shared
+            .into_iter()
+            .map(|(_, data_function)| data_function)
+            .filter(|df| df.maintained_by.len() + df.referenced_by.len() > 1)
+            .collect()
+    }
+
+    /// Shared data functions maintained as an ILF by more than one application.
+    pub fn conflicting_data_functions(&self) -> Vec<SharedDataFunction> {
+        self.shared_data_functions()
+            .into_iter()
+            .filter(SharedDataFunction::is_conflicting)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::basic_functional_component::{
+        BasicFunctionalComponent, ElementaryDataReferenced, FileRegistry,
+    };
+    use crate::project::metadata::{CountType, Metadata};
+    use crate::project::weighting_factors::WeightingFactors;
+
+    fn application(name: &str, components: &[(&str, FunctionalClassification)]) -> Project {
+        let mut proj = Project::new();
+        let mut wf = WeightingFactors::new();
+
+        wf.set_referenced_logical_file([7, 10, 15])
+            .set_external_input([3, 4, 6]);
+
+        proj.set_metadata(Metadata::new(name, CountType::Application))
+            .set_weighting_factors(wf);
+
+        for (component, fc) in components {
+            let mut bfc = BasicFunctionalComponent::new(*component, fc.clone());
+
+            bfc.set_edr(ElementaryDataReferenced::new(4, 0))
+                .set_file_registry(FileRegistry::new(1, 0));
+
+            proj.add_bfc(bfc);
+        }
+
+        proj
+    }
+
+    #[test]
+    fn should_aggregate_applications() {
+        let mut portfolio = Portfolio::new();
+
+        portfolio
+            .add_application(application(
+                "Billing",
+                &[
+                    ("Invoice", FunctionalClassification::InternalLogicalFile),
+                    ("Customer", FunctionalClassification::ExternalInterfaceFile),
+                    ("Issue invoice", FunctionalClassification::ExternalInput),
+                ],
+            ))
+            .add_application(application(
+                "CRM",
+                &[("customer ", FunctionalClassification::InternalLogicalFile)],
+            ));
+
+        let sizes = portfolio.application_sizes();
+
+        assert_eq!(sizes.len(), 2);
+        assert_eq!(sizes[0].name, "Billing");
+        assert_eq!(sizes[0].function_points_not_adjusted, 17f32);
+        assert_eq!(sizes[1].function_points_not_adjusted, 7f32);
+        assert_eq!(portfolio.total_function_point_not_adjusted(), 24f32);
+        assert_eq!(portfolio.total_summary().internal_logical_file, [2, 0, 0]);
+
+        assert_eq!(
+            portfolio.shared_data_functions(),
+            vec![SharedDataFunction {
+                name: "Customer".to_string(),
+                maintained_by: vec!["CRM".to_string()],
+                referenced_by: vec!["Billing".to_string()],
+            }]
+        );
+        assert!(portfolio.conflicting_data_functions().is_empty());
+    }
+
+    #[test]
+    fn should_flag_data_function_maintained_twice() {
+        let mut portfolio = Portfolio::new();
+
+        portfolio
+            .add_application(application(
+                "Sales",
+                &[("Product", FunctionalClassification::InternalLogicalFile)],
+            ))
+            .add_application(application(
+                "Stock",
+                &[("Product", FunctionalClassification::InternalLogicalFile)],
+            ));
+
+        let conflicts = portfolio.conflicting_data_functions();
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].maintained_by, vec!["Sales", "Stock"]);
+    }
+}
@@ -1,25 +1,105 @@
 use adjustment_factors::AdjustmentFactors;
-use basic_functional_component::{BasicFunctionalComponent, Complexity, FunctionalClassification};
+use basic_functional_component::{
+    BasicFunctionalComponent, ElementaryDataReferenced, FileRegistry,
+};
+use diff::ProjectDiff;
+use early_quick::{EarlyQuickRange, EarlyQuickTable};
+use grouping::{GroupReconciliation, GroupSize, GROUP_SEPARATOR};
+use metadata::Metadata;
+use planning::{CutLine, Priority, ReleaseSize};
+use progress::{BurnUpPoint, DeliveryStatus, EarnedValue};
+use report::Report;
+use simple_function_points::SimpleFunctionPoints;
 use summary::Summary;
+use traceability_matrix::TraceabilityMatrix;
+use value_adjustment::ValueAdjustment;
 use weighting_factors::WeightingFactors;
 
 pub mod adjustment_factors;
+pub mod audit;
 pub mod basic_functional_component;
+pub mod diff;
+pub mod early_quick;
+pub mod general_system_characteristics;
+pub mod grouping;
+pub mod metadata;
+pub mod planning;
+pub mod progress;
+pub mod report;
+pub mod simple_function_points;
 pub mod summary;
+pub mod traceability_matrix;
+pub mod value_adjustment;
 pub mod weighting_factors;
 
-#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "StoredProject"))]
+#[cfg_attr(feature = "python", pyo3::pyclass(from_py_object, module = "fpa_rs"))]
+#[derive(Debug, Default, Clone)]
 pub struct Project {
+    metadata: Metadata,
     basic_functional_components: Vec<BasicFunctionalComponent>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     summary: Summary,
     adjustment_factors: AdjustmentFactors,
     weighting_factors: WeightingFactors,
+    value_adjustment: ValueAdjustment,
+    early_quick_table: EarlyQuickTable,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     total_function_point_not_adjusted: f32,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     total_influence_factor: u32,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     final_adjustment_factor: f32,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     final_adjusted_function_points: f32,
     cost_per_hour: f32,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     total_cost: f32,
+    actual_hours: Vec<(u32, f32)>,
+    earned_snapshots: Vec<(u32, f32)>,
+}
+
+/// Serialized form of a project, whose summary and totals are computed again
+/// on load.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct StoredProject {
+    metadata: Metadata,
+    basic_functional_components: Vec<BasicFunctionalComponent>,
+    adjustment_factors: AdjustmentFactors,
+    weighting_factors: WeightingFactors,
+    value_adjustment: ValueAdjustment,
+    early_quick_table: EarlyQuickTable,
+    cost_per_hour: f32,
+    #[serde(default)]
+    actual_hours: Vec<(u32, f32)>,
+    #[serde(default)]
+    earned_snapshots: Vec<(u32, f32)>,
+}
+
+#[cfg(feature = "serde")]
+impl From<StoredProject> for Project {
+    fn from(stored: StoredProject) -> Self {
+        let mut project = Project::new();
+
+        project
+            .set_metadata(stored.metadata)
+            .set_weighting_factors(stored.weighting_factors)
+            .set_value_adjustment(stored.value_adjustment)
+            .set_adjustment_factors(stored.adjustment_factors)
+            .set_early_quick_table(stored.early_quick_table)
+            .set_cost_per_hour(stored.cost_per_hour);
+
+        for bfc in stored.basic_functional_components {
+            project.add_bfc(bfc);
+        }
+
+        project.actual_hours = stored.actual_hours;
+        project.earned_snapshots = stored.earned_snapshots;
+
+        project
+    }
 }
 
 impl Project {
@@ -28,6 +108,13 @@ impl Project {
         Project::default()
     }
 
+    /// Set the project's metadata.
+    pub fn set_metadata(&mut self, metadata: Metadata) -> &mut Self {
+        self.metadata = metadata;
+
+        self
+    }
+
     /// Add Basic Functional Component to Project.
     pub fn add_bfc(&mut self, bfc: BasicFunctionalComponent) -> &mut Self {
         self.increment_summary_table(&bfc);
@@ -37,10 +124,59 @@ impl Project {
         self
     }
 
+    /// Set the Elementary Data Referenced of the component at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set_bfc_edr(&mut self, index: usize, edr: ElementaryDataReferenced) -> &mut Self {
+        self.basic_functional_components[index].set_edr(edr);
+        self.compute_summary_table();
+
+        self
+    }
+
+    /// Set the File Registry of the component at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set_bfc_file_registry(&mut self, index: usize, fr: FileRegistry) -> &mut Self {
+        self.basic_functional_components[index].set_file_registry(fr);
+        self.compute_summary_table();
+
+        self
+    }
+
+    /// Replace the component at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn replace_bfc(&mut self, index: usize, bfc: BasicFunctionalComponent) -> &mut Self {
+        self.basic_functional_components[index] = bfc;
+        self.compute_summary_table();
+
+        self
+    }
+
+    /// Remove and return the component at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_bfc(&mut self, index: usize) -> BasicFunctionalComponent {
+        let bfc = self.basic_functional_components.remove(index);
+
+        self.compute_summary_table();
+
+        bfc
+    }
+
     /// Set the Weighting Factors.
     pub fn set_weighting_factors(&mut self, wf: WeightingFactors) -> &mut Self {
         self.weighting_factors = wf;
-        self.compute_fafp();
+        self.compute_tfna();
 
         self
     }
@@ -49,8 +185,22 @@ impl Project {
     pub fn set_adjustment_factors(&mut self, af: AdjustmentFactors) -> &mut Self {
         self.adjustment_factors = af;
         self.total_influence_factor = self.adjustment_factors.sum();
-        self.final_adjustment_factor = 0.65f32 + (0.01f32 * self.total_influence_factor as f32);
-        self.compute_fafp();
+        self.compute_vaf();
+
+        self
+    }
+
+    /// Set how the Value Adjustment Factor is derived.
+    pub fn set_value_adjustment(&mut self, va: ValueAdjustment) -> &mut Self {
+        self.value_adjustment = va;
+        self.compute_vaf();
+
+        self
+    }
+
+    /// Set the Early & Quick FP table.
+    pub fn set_early_quick_table(&mut self, table: EarlyQuickTable) -> &mut Self {
+        self.early_quick_table = table;
 
         self
     }
@@ -63,6 +213,35 @@ impl Project {
         self
     }
 
+    /// Set the delivery status of the component at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set_bfc_status(&mut self, index: usize, status: DeliveryStatus) -> &mut Self {
+        self.basic_functional_components[index].set_status(status);
+
+        self
+    }
+
+    /// Record hours actually spent in a period.
+    pub fn record_actual_hours(&mut self, period: u32, hours: f32) -> &mut Self {
+        self.actual_hours.push((period, hours));
+
+        self
+    }
+
+    /// Record the function points earned so far as the progress of a period.
+    pub fn record_progress(&mut self, period: u32) -> &mut Self {
+        let earned = self.earned_function_points();
+
+        self.earned_snapshots.retain(|(p, _)| *p != period);
+        self.earned_snapshots.push((period, earned));
+        self.earned_snapshots.sort_by_key(|(p, _)| *p);
+
+        self
+    }
+
     /// Get a reference to the project's total function point not adjusted.
     pub fn total_function_point_not_adjusted(&self) -> f32 {
         self.total_function_point_not_adjusted
@@ -88,6 +267,401 @@ impl Project {
         self.total_cost
     }
 
+    /// Get a reference to the project's metadata.
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Get a reference to the project's summary.
+    pub fn summary(&self) -> &Summary {
+        &self.summary
+    }
+
+    /// Get a reference to the project's adjustment factors.
+    pub fn adjustment_factors(&self) -> &AdjustmentFactors {
+        &self.adjustment_factors
+    }
+
+    /// Get a reference to the project's weighting factors.
+    pub fn weighting_factors(&self) -> &WeightingFactors {
+        &self.weighting_factors
+    }
+
+    /// Get a reference to the project's value adjustment mode.
+    pub fn value_adjustment(&self) -> &ValueAdjustment {
+        &self.value_adjustment
+    }
+
+    /// Get a reference to the project's cost per hour.
+    pub fn cost_per_hour(&self) -> f32 {
+        self.cost_per_hour
+    }
+
+    /// Build the project's count report.
+    pub fn report(&self) -> Report<'_> {
+        Report::new(self)
+    }
+
+    /// Requirement IDs traced by the components, in order of appearance.
+    pub fn requirements(&self) -> Vec<&str> {
+        let mut requirements: Vec<&str> = Vec::new();
+
+        for bfc in &self.basic_functional_components {
+            for requirement in bfc.traceability().requirements() {
+                if !requirements.contains(&requirement.as_str()) {
+                    requirements.push(requirement);
+                }
+            }
+        }
+
+        requirements
+    }
+
+    /// Components produced by a requirement.
+    pub fn components_for_requirement(&self, requirement: &str) -> Vec<&BasicFunctionalComponent> {
+        self.basic_functional_components
+            .iter()
+            .filter(|bfc| bfc.traceability().traces(requirement))
+            .collect()
+    }
+
+    /// Unadjusted function points contributed by a requirement.
+    ///
+    /// A component traced by several requirements contributes its whole
+    /// weight to each of them.
+    pub fn requirement_function_points(&self, requirement: &str) -> f32 {
+        self.components_for_requirement(requirement)
+            .iter()
+            .map(|bfc| self.weighting_factors.weight(bfc))
+            .sum::<u32>() as f32
+    }
+
+    /// Build the project's traceability matrix report.
+    pub fn traceability_matrix(&self) -> TraceabilityMatrix<'_> {
+        TraceabilityMatrix::new(self)
+    }
+
+    /// Group labels used by the components, including their ancestors, in
+    /// order of appearance.
+    pub fn groups(&self) -> Vec<String> {
+        let mut groups: Vec<String> = Vec::new();
+
+        for bfc in &self.basic_functional_components {
+            for label in bfc.groups() {
+                let mut end = 0;
+
+                for part in label.split(GROUP_SEPARATOR) {
+                    end += part.len();
+
+                    if !groups.iter().any(|group| *group == label[..end]) {
+                        groups.push(label[..end].to_string());
+                    }
+
+                    end += GROUP_SEPARATOR.len_utf8();
+                }
+            }
+        }
+
+        groups
+    }
+
+    /// Size of a group, including its subgroups.
+    pub fn group_size(&self, label: &str) -> GroupSize {
+        let mut size = GroupSize {
+            label: label.to_string(),
+            ..GroupSize::default()
+        };
+
+        for bfc in self
+            .basic_functional_components
+            .iter()
+            .filter(|bfc| bfc.belongs_to(label))
+        {
+            size.components += 1;
+            size.summary.increment(bfc);
+            size.function_points_not_adjusted += self.weighting_factors.weight(bfc) as f32;
+        }
+
+        size
+    }
+
+    /// Size of every group.
+    pub fn group_sizes(&self) -> Vec<GroupSize> {
+        self.groups()
+            .iter()
+            .map(|label| self.group_size(label))
+            .collect()
+    }
+
+    /// Reconcile top-level group totals with the project total.
+    pub fn group_reconciliation(&self) -> GroupReconciliation {
+        let mut reconciliation = GroupReconciliation {
+            project_total: self.total_function_point_not_adjusted,
+            ..GroupReconciliation::default()
+        };
+
+        for bfc in &self.basic_functional_components {
+            let weight = self.weighting_factors.weight(bfc) as f32;
+            let mut roots: Vec<&str> = bfc
+                .groups()
+                .iter()
+                .map(|label| grouping::root_group(label))
+                .collect();
+
+            roots.sort_unstable();
+            roots.dedup();
+
+            if roots.is_empty() {
+                reconciliation.ungrouped += weight;
+            } else {
+                reconciliation.groups_total += weight * roots.len() as f32;
+                reconciliation.double_counted += weight * (roots.len() - 1) as f32;
+            }
+        }
+
+        reconciliation
+    }
+
+    /// Adjusted function points of a single component.
+    pub fn bfc_adjusted_function_points(&self, bfc: &BasicFunctionalComponent) -> f32 {
+        self.weighting_factors.weight(bfc) as f32 * self.final_adjustment_factor
+    }
+
+    /// Cost of a single component.
+    pub fn bfc_cost(&self, bfc: &BasicFunctionalComponent) -> f32 {
+        self.bfc_adjusted_function_points(bfc) * self.cost_per_hour
+    }
+
+    /// Size and cost of each release, in order of first appearance.
+    ///
+    /// Components without a release are left out.
+    pub fn release_plan(&self) -> Vec<ReleaseSize> {
+        let mut plan: Vec<ReleaseSize> = Vec::new();
+
+        for bfc in &self.basic_functional_components {
+            let release = match bfc.release() {
+                Some(release) => release,
+                None => continue,
+            };
+
+            let position = match plan.iter().position(|size| size.release == release) {
+                Some(position) => position,
+                None => {
+                    plan.push(ReleaseSize {
+                        release: release.to_string(),
+                        ..ReleaseSize::default()
+                    });
+                    plan.len() - 1
+                }
+            };
+
+            let size = &mut plan[position];
+
+            size.components += 1;
+            size.function_points_not_adjusted += self.weighting_factors.weight(bfc) as f32;
+            size.adjusted_function_points += self.bfc_adjusted_function_points(bfc);
+            size.cost += self.bfc_cost(bfc);
+        }
+
+        let (mut function_points, mut cost) = (0f32, 0f32);
+
+        for size in plan.iter_mut() {
+            function_points += size.adjusted_function_points;
+            cost += size.cost;
+            size.cumulative_adjusted_function_points = function_points;
+            size.cumulative_cost = cost;
+        }
+
+        plan
+    }
+
+    /// Components ordered by MoSCoW priority, unprioritised ones after the
+    /// Coulds and before the Won'ts.
+    pub fn prioritised_backlog(&self) -> Vec<&BasicFunctionalComponent> {
+        let mut backlog: Vec<&BasicFunctionalComponent> =
+            self.basic_functional_components.iter().collect();
+
+        backlog.sort_by_key(|bfc| {
+            let priority = bfc.priority().copied();
+
+            (priority.unwrap_or(Priority::Could), priority.is_none())
+        });
+
+        backlog
+    }
+
+    /// Where a budget falls in the prioritised backlog.
+    pub fn cut_line_by_budget(&self, budget: f32) -> CutLine<'_> {
+        self.cut_line(budget, |bfc| self.bfc_cost(bfc))
+    }
+
+    /// Where a capacity, in adjusted function points, falls in the prioritised backlog.
+    pub fn cut_line_by_capacity(&self, capacity: f32) -> CutLine<'_> {
+        self.cut_line(capacity, |bfc| self.bfc_adjusted_function_points(bfc))
+    }
+
+    fn cut_line<F>(&self, limit: f32, measure: F) -> CutLine<'_>
+    where
+        F: Fn(&BasicFunctionalComponent) -> f32,
+    {
+        let mut cut_line = CutLine::default();
+        let mut used = 0f32;
+        let mut reached = false;
+
+        for bfc in self.prioritised_backlog() {
+            if bfc.priority() == Some(&Priority::Wont) {
+                cut_line.excluded.push(bfc);
+                continue;
+            }
+
+            reached |= used + measure(bfc) > limit;
+
+            if !reached {
+                used += measure(bfc);
+                cut_line.adjusted_function_points += self.bfc_adjusted_function_points(bfc);
+                cut_line.cost += self.bfc_cost(bfc);
+                cut_line.included.push(bfc);
+            } else {
+                cut_line.excluded.push(bfc);
+            }
+        }
+
+        cut_line
+    }
+
+    /// Adjusted function points planned to be delivered up to a period.
+    pub fn planned_function_points(&self, period: u32) -> f32 {
+        self.basic_functional_components
+            .iter()
+            .filter(|bfc| bfc.planned_period().is_some_and(|p| p <= period))
+            .map(|bfc| self.bfc_adjusted_function_points(bfc))
+            .sum()
+    }
+
+    /// Adjusted function points earned by the components' current status.
+    pub fn earned_function_points(&self) -> f32 {
+        self.basic_functional_components
+            .iter()
+            .map(|bfc| self.bfc_adjusted_function_points(bfc) * bfc.status().completion())
+            .sum()
+    }
+
+    /// Earned value figures at the end of a period, from the progress recorded
+    /// up to it.
+    ///
+    /// Function points are turned into hours with a productivity rate, such as
+    /// the one calibrated from a `Repository`, so that planned and earned
+    /// value are priced like the actual hours.
+    pub fn earned_value(&self, period: u32, hours_per_function_point: f32) -> EarnedValue {
+        let planned_function_points = self.planned_function_points(period);
+        let earned_function_points = self
+            .earned_snapshots
+            .iter()
+            .rev()
+            .find(|(p, _)| *p <= period)
+            .map_or(0f32, |(_, earned)| *earned);
+        let actual_hours: f32 = self
+            .actual_hours
+            .iter()
+            .filter(|(p, _)| *p <= period)
+            .map(|(_, hours)| hours)
+            .sum();
+
+        let planned_value = planned_function_points * hours_per_function_point * self.cost_per_hour;
+        let earned_value = earned_function_points * hours_per_function_point * self.cost_per_hour;
+        let actual_cost = actual_hours * self.cost_per_hour;
+
+        EarnedValue {
+            period,
+            planned_function_points,
+            earned_function_points,
+            planned_value,
+            earned_value,
+            actual_cost,
+            schedule_performance_index: if planned_value > 0f32 {
+                Some(earned_value / planned_value)
+            } else {
+                None
+            },
+            cost_performance_index: if actual_cost > 0f32 {
+                Some(earned_value / actual_cost)
+            } else {
+                None
+            },
+        }
+    }
+
+    /// Burn-up chart data over every planned or recorded period.
+    pub fn burn_up(&self) -> Vec<BurnUpPoint> {
+        let scope: f32 = self
+            .basic_functional_components
+            .iter()
+            .map(|bfc| self.bfc_adjusted_function_points(bfc))
+            .sum();
+
+        let mut periods: Vec<u32> = self
+            .basic_functional_components
+            .iter()
+            .filter_map(|bfc| bfc.planned_period())
+            .chain(self.earned_snapshots.iter().map(|(p, _)| *p))
+            .collect();
+
+        periods.sort_unstable();
+        periods.dedup();
+
+        periods
+            .into_iter()
+            .map(|period| BurnUpPoint {
+                period,
+                scope,
+                planned: self.planned_function_points(period),
+                earned: self
+                    .earned_snapshots
+                    .iter()
+                    .rev()
+                    .find(|(p, _)| *p <= period)
+                    .map(|(_, earned)| *earned),
+            })
+            .collect()
+    }
+
+    /// Compare this count with a revised one.
+    pub fn diff(&self, revised: &Project) -> ProjectDiff {
+        ProjectDiff::new(self, revised)
+    }
+
+    /// Get a reference to the project's basic functional components.
+    pub fn basic_functional_components(&self) -> &[BasicFunctionalComponent] {
+        &self.basic_functional_components
+    }
+
+    /// Size the project with Simple Function Points (SiFP).
+    pub fn simple_function_points(&self) -> SimpleFunctionPoints {
+        let data_groups = self
+            .basic_functional_components
+            .iter()
+            .filter(|bfc| bfc.functional_classification().is_data_function())
+            .count() as u32;
+
+        SimpleFunctionPoints::new(
+            self.basic_functional_components.len() as u32 - data_groups,
+            data_groups,
+        )
+    }
+
+    /// Size the project with Early & Quick FP.
+    pub fn early_quick_function_points(&self) -> EarlyQuickRange {
+        self.basic_functional_components
+            .iter()
+            .map(|bfc| *self.early_quick_table.range(bfc.early_quick_level()))
+            .fold(EarlyQuickRange::default(), |total, range| total + range)
+    }
+
+    fn compute_vaf(&mut self) {
+        self.final_adjustment_factor = self.value_adjustment.factor(self.total_influence_factor);
+        self.compute_fafp();
+    }
+
     fn compute_fafp(&mut self) {
         self.final_adjusted_function_points =
             self.total_function_point_not_adjusted * self.final_adjustment_factor;
@@ -99,25 +673,21 @@ impl Project {
         self.total_cost = self.final_adjusted_function_points * self.cost_per_hour;
     }
 
-    fn increment_summary_table(&mut self, bfc: &BasicFunctionalComponent) {
-        let level = match bfc.complexity() {
-            Complexity::Simple => 0,
-            Complexity::Middle => 1,
-            Complexity::Complex => 2,
-        };
+    fn compute_summary_table(&mut self) {
+        let components = std::mem::take(&mut self.basic_functional_components);
 
-        match bfc.functional_classification() {
-            FunctionalClassification::ExternalInput => self.summary.external_input[level] += 1,
-            FunctionalClassification::ExternalOutput => self.summary.external_output[level] += 1,
-            FunctionalClassification::ExternalInterfaceFile => {
-                self.summary.external_interface_file[level] += 1
-            }
-            FunctionalClassification::InternalLogicalFile => {
-                self.summary.internal_logical_file[level] += 1
-            }
-            FunctionalClassification::ExternalQuery => self.summary.external_query[level] += 1,
+        self.summary = Summary::default();
+
+        for bfc in &components {
+            self.increment_summary_table(bfc);
         }
 
+        self.basic_functional_components = components;
+        self.compute_tfna();
+    }
+
+    fn increment_summary_table(&mut self, bfc: &BasicFunctionalComponent) {
+        self.summary.increment(bfc);
         self.compute_fafp();
     }
 
@@ -166,8 +736,9 @@ impl Project {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::project::basic_functional_component::ElementaryDataReferenced;
-    use crate::project::basic_functional_component::FileRegistry;
+    use crate::project::basic_functional_component::FunctionalClassification;
+    use crate::project::early_quick::EarlyQuickLevel;
+    use crate::project::planning::Priority;
 
     #[test]
     fn should_calculate_correctly() {
@@ -252,4 +823,451 @@ mod tests {
         );
         assert_eq!(2716f32, proj.total_cost());
     }
+
+    #[test]
+    fn should_recompute_when_editing_components_and_weights() {
+        let mut proj = Project::new();
+
+        proj.add_bfc(BasicFunctionalComponent::new(
+            "Customer",
+            FunctionalClassification::InternalLogicalFile,
+        ))
+        .set_bfc_edr(0, ElementaryDataReferenced::new(10, 0))
+        .set_bfc_file_registry(0, FileRegistry::new(1, 0));
+
+        assert_eq!(proj.summary().internal_logical_file, [1, 0, 0]);
+        assert_eq!(0f32, proj.total_function_point_not_adjusted());
+
+        let mut wf = WeightingFactors::new();
+
+        wf.set_referenced_logical_file([7, 10, 15]);
+        proj.set_weighting_factors(wf)
+            .set_bfc_file_registry(0, FileRegistry::new(6, 0));
+
+        assert_eq!(proj.summary().internal_logical_file, [0, 1, 0]);
+        assert_eq!(10f32, proj.total_function_point_not_adjusted());
+
+        let mut tariff =
+            BasicFunctionalComponent::new("Tariff", FunctionalClassification::InternalLogicalFile);
+
+        tariff
+            .set_edr(ElementaryDataReferenced::new(5, 0))
+            .set_file_registry(FileRegistry::new(1, 0));
+        proj.replace_bfc(0, tariff);
+
+        assert_eq!(proj.summary().internal_logical_file, [1, 0, 0]);
+        assert_eq!(7f32, proj.total_function_point_not_adjusted());
+        assert_eq!(proj.remove_bfc(0).name(), "Tariff");
+        assert_eq!(proj.summary().internal_logical_file, [0, 0, 0]);
+        assert_eq!(0f32, proj.total_function_point_not_adjusted());
+    }
+
+    #[test]
+    fn should_size_groups() {
+        let mut proj = Project::new();
+        let mut wf = WeightingFactors::new();
+
+        wf.set_referenced_logical_file([7, 10, 15])
+            .set_external_input([3, 4, 6]);
+        proj.set_weighting_factors(wf);
+
+        let components = [
+            (
+                "Invoice",
+                FunctionalClassification::InternalLogicalFile,
+                vec!["billing/invoices"],
+            ),
+            (
+                "Issue invoice",
+                FunctionalClassification::ExternalInput,
+                vec!["billing/invoices"],
+            ),
+            (
+                "Pay",
+                FunctionalClassification::ExternalInput,
+                vec!["billing/payments", "portal"],
+            ),
+            (
+                "Settings",
+                FunctionalClassification::InternalLogicalFile,
+                vec![],
+            ),
+        ];
+
+        for (name, fc, groups) in components.iter() {
+            let mut bfc = BasicFunctionalComponent::new(*name, fc.clone());
+
+            bfc.set_edr(ElementaryDataReferenced::new(4, 0))
+                .set_file_registry(FileRegistry::new(1, 0));
+
+            for group in groups {
+                bfc.add_group(*group);
+            }
+
+            proj.add_bfc(bfc);
+        }
+
+        assert_eq!(
+            proj.groups(),
+            vec!["billing", "billing/invoices", "billing/payments", "portal"]
+        );
+
+        let billing = proj.group_size("billing");
+
+        assert_eq!(billing.components, 3);
+        assert_eq!(billing.summary.external_input, [2, 0, 0]);
+        assert_eq!(billing.function_points_not_adjusted, 13f32);
+        assert_eq!(
+            proj.group_size("billing/payments")
+                .function_points_not_adjusted,
+            3f32
+        );
+        assert_eq!(proj.group_size("bill").components, 0);
+
+        let reconciliation = proj.group_reconciliation();
+
+        assert_eq!(reconciliation.groups_total, 16f32);
+        assert_eq!(reconciliation.ungrouped, 7f32);
+        assert_eq!(reconciliation.double_counted, 3f32);
+        assert_eq!(reconciliation.project_total, 20f32);
+        assert!(reconciliation.is_reconciled());
+    }
+
+    #[test]
+    fn should_plan_releases() {
+        let mut proj = Project::new();
+        let mut wf = WeightingFactors::new();
+
+        wf.set_referenced_logical_file([7, 10, 15])
+            .set_external_input([3, 4, 6]);
+        proj.set_weighting_factors(wf)
+            .set_value_adjustment(ValueAdjustment::Disabled)
+            .set_cost_per_hour(10f32);
+
+        let components = [
+            (
+                "Customer",
+                FunctionalClassification::InternalLogicalFile,
+                "R1",
+                Priority::Must,
+            ),
+            (
+                "Create customer",
+                FunctionalClassification::ExternalInput,
+                "R1",
+                Priority::Should,
+            ),
+            (
+                "Import customers",
+                FunctionalClassification::ExternalInput,
+                "R2",
+                Priority::Wont,
+            ),
+            (
+                "Delete customer",
+                FunctionalClassification::ExternalInput,
+                "R2",
+                Priority::Could,
+            ),
+            (
+                "Product",
+                FunctionalClassification::InternalLogicalFile,
+                "R2",
+                Priority::Must,
+            ),
+        ];
+
+        for (name, fc, release, priority) in components.iter() {
+            let mut bfc = BasicFunctionalComponent::new(*name, fc.clone());
+
+            bfc.set_edr(ElementaryDataReferenced::new(4, 0))
+                .set_file_registry(FileRegistry::new(1, 0))
+                .set_release(*release)
+                .set_priority(*priority);
+
+            proj.add_bfc(bfc);
+        }
+
+        let plan = proj.release_plan();
+
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].release, "R1");
+        assert_eq!(plan[0].adjusted_function_points, 10f32);
+        assert_eq!(plan[1].cost, 130f32);
+        assert_eq!(plan[1].cumulative_adjusted_function_points, 23f32);
+        assert_eq!(plan[1].cumulative_cost, 230f32);
+
+        let backlog: Vec<&str> = proj
+            .prioritised_backlog()
+            .iter()
+            .map(|bfc| bfc.name().as_str())
+            .collect();
+
+        assert_eq!(
+            backlog,
+            vec![
+                "Customer",
+                "Product",
+                "Create customer",
+                "Delete customer",
+                "Import customers"
+            ]
+        );
+
+        let cut_line = proj.cut_line_by_budget(190f32);
+
+        assert_eq!(cut_line.included.len(), 3);
+        assert_eq!(cut_line.cost, 170f32);
+        assert_eq!(cut_line.excluded[0].name(), "Delete customer");
+
+        let cut_line = proj.cut_line_by_capacity(100f32);
+
+        assert_eq!(cut_line.included.len(), 4);
+        assert_eq!(cut_line.excluded[0].name(), "Import customers");
+    }
+
+    #[test]
+    fn should_plan_unprioritised_components_before_wont() {
+        let mut proj = Project::new();
+        let mut wont = BasicFunctionalComponent::new(
+            "Import customers",
+            FunctionalClassification::ExternalInput,
+        );
+
+        wont.set_priority(Priority::Wont);
+        proj.add_bfc(wont).add_bfc(BasicFunctionalComponent::new(
+            "Create customer",
+            FunctionalClassification::ExternalInput,
+        ));
+
+        assert_eq!(proj.prioritised_backlog()[0].name(), "Create customer");
+
+        let cut_line = proj.cut_line_by_capacity(1e9);
+
+        assert_eq!(cut_line.included.len(), 1);
+        assert_eq!(cut_line.excluded[0].name(), "Import customers");
+    }
+
+    #[test]
+    fn should_track_earned_value() {
+        let mut proj = Project::new();
+        let mut wf = WeightingFactors::new();
+
+        wf.set_referenced_logical_file([7, 10, 15])
+            .set_external_input([3, 4, 6]);
+        proj.set_weighting_factors(wf)
+            .set_value_adjustment(ValueAdjustment::Disabled)
+            .set_cost_per_hour(10f32);
+
+        let components = [
+            ("Customer", FunctionalClassification::InternalLogicalFile, 1),
+            (
+                "Create customer",
+                FunctionalClassification::ExternalInput,
+                1,
+            ),
+            ("Product", FunctionalClassification::InternalLogicalFile, 2),
+        ];
+
+        for (name, fc, period) in components.iter() {
+            let mut bfc = BasicFunctionalComponent::new(*name, fc.clone());
+
+            bfc.set_edr(ElementaryDataReferenced::new(4, 0))
+                .set_file_registry(FileRegistry::new(1, 0))
+                .set_planned_period(*period);
+
+            proj.add_bfc(bfc);
+        }
+
+        let ev = proj.earned_value(1, 2f32);
+
+        assert_eq!(ev.planned_function_points, 10f32);
+        assert_eq!(ev.earned_function_points, 0f32);
+        assert_eq!(ev.schedule_performance_index, Some(0f32));
+        assert_eq!(ev.cost_performance_index, None);
+
+        proj.set_bfc_status(0, DeliveryStatus::Accepted)
+            .set_bfc_status(1, DeliveryStatus::InProgress(50))
+            .record_actual_hours(1, 10f32)
+            .record_progress(1);
+
+        proj.set_bfc_status(2, DeliveryStatus::Delivered);
+
+        let ev = proj.earned_value(1, 2f32);
+
+        assert_eq!(ev.earned_function_points, 8.5f32);
+        assert_eq!(ev.planned_value, 200f32);
+        assert_eq!(ev.earned_value, 170f32);
+        assert_eq!(ev.actual_cost, 100f32);
+        assert_eq!(ev.schedule_performance_index, Some(0.85f32));
+        assert_eq!(ev.cost_performance_index, Some(1.7f32));
+        assert_eq!(proj.earned_value(0, 2f32).earned_function_points, 0f32);
+
+        let burn_up = proj.burn_up();
+
+        assert_eq!(burn_up.len(), 2);
+        assert_eq!(burn_up[0].scope, 17f32);
+        assert_eq!(burn_up[0].earned, Some(8.5f32));
+        assert_eq!(burn_up[1].planned, 17f32);
+        assert_eq!(burn_up[1].earned, Some(8.5f32));
+    }
+
+    #[test]
+    fn should_not_adjust_when_value_adjustment_is_disabled() {
+        let mut proj = Project::new();
+
+        let mut wf = WeightingFactors::new();
+
+        wf.set_referenced_logical_file([7, 10, 15])
+            .set_external_input([3, 4, 6]);
+
+        let mut ilf = BasicFunctionalComponent::new(
+            "Customer",
+            FunctionalClassification::InternalLogicalFile,
+        );
+        let mut ei = BasicFunctionalComponent::new(
+            "Create customer",
+            FunctionalClassification::ExternalInput,
+        );
+
+        ilf.set_edr(ElementaryDataReferenced::new(10, 0))
+            .set_file_registry(FileRegistry::new(1, 0));
+        ei.set_edr(ElementaryDataReferenced::new(5, 0))
+            .set_file_registry(FileRegistry::new(1, 0));
+
+        proj.set_weighting_factors(wf)
+            .set_adjustment_factors(AdjustmentFactors::new([5; 14]))
+            .set_cost_per_hour(10f32)
+            .add_bfc(ilf)
+            .add_bfc(ei);
+
+        assert_eq!("1.35", format!("{:.2}", proj.final_adjustment_factor()));
+
+        proj.set_value_adjustment(ValueAdjustment::Disabled);
+
+        assert_eq!(10f32, proj.final_adjusted_function_points());
+        assert_eq!(100f32, proj.total_cost());
+
+        proj.set_value_adjustment(ValueAdjustment::Custom {
+            base: 0.5,
+            step: 0.02,
+        });
+
+        assert_eq!("1.90", format!("{:.2}", proj.final_adjustment_factor()));
+        assert_eq!(
+            "19.00",
+            format!("{:.2}", proj.final_adjusted_function_points())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_serialize_with_metadata() {
+        use crate::project::metadata::{CountType, Metadata};
+
+        let mut proj = Project::new();
+        let mut metadata = Metadata::new("Inventory", CountType::Application);
+
+        metadata.set_boundary("Stock control, excluding purchasing");
+
+        let mut bfc =
+            BasicFunctionalComponent::new("Product", FunctionalClassification::InternalLogicalFile);
+
+        bfc.set_edr(ElementaryDataReferenced::new(4, 0))
+            .set_file_registry(FileRegistry::new(1, 0));
+
+        proj.set_metadata(metadata).add_bfc(bfc);
+
+        let json = serde_json::to_string(&proj).unwrap();
+        let restored: Project = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.metadata(), proj.metadata());
+        assert_eq!(
+            restored.basic_functional_components(),
+            proj.basic_functional_components()
+        );
+        assert_eq!(
+            restored.metadata().boundary(),
+            "Stock control, excluding purchasing"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_recompute_derived_fields_on_deserialize() {
+        let mut proj = Project::new();
+        let mut wf = WeightingFactors::new();
+        let mut bfc =
+            BasicFunctionalComponent::new("Product", FunctionalClassification::InternalLogicalFile);
+
+        wf.set_referenced_logical_file([7, 10, 15]);
+        bfc.set_edr(ElementaryDataReferenced::new(4, 0))
+            .set_file_registry(FileRegistry::new(1, 0));
+        proj.set_weighting_factors(wf).add_bfc(bfc);
+
+        let mut json = serde_json::to_value(&proj).unwrap();
+
+        assert!(json.get("total_function_point_not_adjusted").is_none());
+        assert!(json["basic_functional_components"][0]
+            .get("complexity")
+            .is_none());
+
+        json["total_function_point_not_adjusted"] = serde_json::json!(999);
+        json["basic_functional_components"][0]["complexity"] = serde_json::json!("Complex");
+        json.as_object_mut().unwrap().remove("earned_snapshots");
+        json["basic_functional_components"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("status");
+
+        let restored: Project = serde_json::from_value(json).unwrap();
+
+        assert_eq!(restored.total_function_point_not_adjusted(), 7f32);
+        assert_eq!(
+            restored.basic_functional_components()[0].complexity(),
+            &basic_functional_component::Complexity::Simple
+        );
+        assert_eq!(restored.summary(), proj.summary());
+    }
+
+    #[test]
+    fn should_size_without_edr_and_file_registry() {
+        let mut proj = Project::new();
+
+        for i in 0..10 {
+            proj.add_bfc(BasicFunctionalComponent::new(
+                format!("Transaction {}", i),
+                FunctionalClassification::ExternalInput,
+            ));
+        }
+
+        let mut process = BasicFunctionalComponent::new(
+            "Customer maintenance",
+            FunctionalClassification::ExternalInput,
+        );
+
+        process.set_early_quick_level(EarlyQuickLevel::TypicalProcessSmall);
+
+        proj.add_bfc(process)
+            .add_bfc(BasicFunctionalComponent::new(
+                "Customer",
+                FunctionalClassification::InternalLogicalFile,
+            ))
+            .add_bfc(BasicFunctionalComponent::new(
+                "Currency rates",
+                FunctionalClassification::ExternalInterfaceFile,
+            ));
+
+        let sifp = proj.simple_function_points();
+
+        assert_eq!(sifp.transactions(), 11);
+        assert_eq!(sifp.data_groups(), 2);
+        assert_eq!("64.6", format!("{:.1}", sifp.total()));
+
+        let eqfp = proj.early_quick_function_points();
+
+        assert_eq!("67.4", format!("{:.1}", eqfp.min()));
+        assert_eq!("71.3", format!("{:.1}", eqfp.likely()));
+        assert_eq!("75.4", format!("{:.1}", eqfp.max()));
+    }
 }
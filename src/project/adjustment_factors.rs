@@ -1,58 +1,212 @@
-#[derive(Default, Debug)]
+use super::general_system_characteristics::{GeneralSystemCharacteristic, GscRating, MAX_DEGREE};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyo3::pyclass(from_py_object, module = "fpa_rs"))]
+#[derive(Default, Debug, Clone, PartialEq)]
 pub struct AdjustmentFactors {
-    teleprocessing: u8,
-    distributed_processing: u8,
-    performance: u8,
-    machine_load: u8,
-    transaction_volume: u8,
-    online_data_input: u8,
-    online_updates: u8,
-    user_end_eficiency: u8,
-    processing_complexity: u8,
-    code_reuse: u8,
-    implementation_facility: u8,
-    operation_facility: u8,
-    maintenance_facility: u8,
-    operation_in_multiple_locations: u8,
+    ratings: [GscRating; 14],
 }
 
 impl AdjustmentFactors {
     pub fn new(factors_vec: [u8; 14]) -> Self {
         let mut af = AdjustmentFactors::default();
 
-        af.teleprocessing = factors_vec[0];
-        af.distributed_processing = factors_vec[1];
-        af.performance = factors_vec[2];
-        af.machine_load = factors_vec[3];
-        af.transaction_volume = factors_vec[4];
-        af.online_data_input = factors_vec[5];
-        af.online_updates = factors_vec[6];
-        af.user_end_eficiency = factors_vec[7];
-        af.processing_complexity = factors_vec[8];
-        af.code_reuse = factors_vec[9];
-        af.implementation_facility = factors_vec[10];
-        af.operation_facility = factors_vec[11];
-        af.maintenance_facility = factors_vec[12];
-        af.operation_in_multiple_locations = factors_vec[13];
+        for (rating, degree) in af.ratings.iter_mut().zip(factors_vec.iter()) {
+            rating.set_degree(*degree);
+        }
 
         af
     }
 
     pub fn sum(&self) -> u32 {
-        (self.teleprocessing
-            + self.distributed_processing
-            + self.performance
-            + self.machine_load
-            + self.transaction_volume
-            + self.online_data_input
-            + self.online_updates
-            + self.user_end_eficiency
-            + self.processing_complexity
-            + self.code_reuse
-            + self.implementation_facility
-            + self.operation_facility
-            + self.maintenance_facility
-            + self.operation_in_multiple_locations) as u32
+        self.ratings
+            .iter()
+            .map(|rating| rating.degree() as u32)
+            .sum()
+    }
+
+    /// Get the rating of a characteristic.
+    pub fn rating(&self, gsc: GeneralSystemCharacteristic) -> &GscRating {
+        &self.ratings[gsc.index()]
+    }
+
+    /// Set the rating of a characteristic.
+    pub fn set_rating(&mut self, gsc: GeneralSystemCharacteristic, rating: GscRating) -> &mut Self {
+        self.ratings[gsc.index()] = rating;
+        self
+    }
+
+    /// Get the degree of influence of a characteristic.
+    pub fn degree(&self, gsc: GeneralSystemCharacteristic) -> u8 {
+        self.ratings[gsc.index()].degree()
+    }
+
+    /// Set the degree of influence of a characteristic, keeping its justification.
+    pub fn set_degree(&mut self, gsc: GeneralSystemCharacteristic, degree: u8) -> &mut Self {
+        self.ratings[gsc.index()].set_degree(degree);
+        self
+    }
+
+    /// Set the justification of a characteristic's rating.
+    pub fn set_justification(
+        &mut self,
+        gsc: GeneralSystemCharacteristic,
+        justification: impl Into<String>,
+    ) -> &mut Self {
+        self.ratings[gsc.index()].set_justification(justification);
+        self
+    }
+
+    /// Characteristics rated above the maximum degree of influence.
+    pub fn out_of_range(&self) -> Vec<GeneralSystemCharacteristic> {
+        GeneralSystemCharacteristic::ALL
+            .iter()
+            .copied()
+            .filter(|gsc| self.degree(*gsc) > MAX_DEGREE)
+            .collect()
+    }
+
+    /// Get the adjustment factors's teleprocessing degree.
+    pub fn teleprocessing(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::Teleprocessing)
+    }
+
+    /// Set the adjustment factors's teleprocessing degree.
+    pub fn set_teleprocessing(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(GeneralSystemCharacteristic::Teleprocessing, degree)
+    }
+
+    /// Get the adjustment factors's distributed processing degree.
+    pub fn distributed_processing(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::DistributedProcessing)
+    }
+
+    /// Set the adjustment factors's distributed processing degree.
+    pub fn set_distributed_processing(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(GeneralSystemCharacteristic::DistributedProcessing, degree)
+    }
+
+    /// Get the adjustment factors's performance degree.
+    pub fn performance(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::Performance)
+    }
+
+    /// Set the adjustment factors's performance degree.
+    pub fn set_performance(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(GeneralSystemCharacteristic::Performance, degree)
+    }
+
+    /// Get the adjustment factors's machine load degree.
+    pub fn machine_load(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::MachineLoad)
+    }
+
+    /// Set the adjustment factors's machine load degree.
+    pub fn set_machine_load(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(GeneralSystemCharacteristic::MachineLoad, degree)
+    }
+
+    /// Get the adjustment factors's transaction volume degree.
+    pub fn transaction_volume(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::TransactionVolume)
+    }
+
+    /// Set the adjustment factors's transaction volume degree.
+    pub fn set_transaction_volume(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(GeneralSystemCharacteristic::TransactionVolume, degree)
+    }
+
+    /// Get the adjustment factors's online data input degree.
+    pub fn online_data_input(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::OnlineDataInput)
+    }
+
+    /// Set the adjustment factors's online data input degree.
+    pub fn set_online_data_input(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(GeneralSystemCharacteristic::OnlineDataInput, degree)
+    }
+
+    /// Get the adjustment factors's online updates degree.
+    pub fn online_updates(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::OnlineUpdates)
+    }
+
+    /// Set the adjustment factors's online updates degree.
+    pub fn set_online_updates(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(GeneralSystemCharacteristic::OnlineUpdates, degree)
+    }
+
+    /// Get the adjustment factors's end user efficiency degree.
+    pub fn end_user_efficiency(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::EndUserEfficiency)
+    }
+
+    /// Set the adjustment factors's end user efficiency degree.
+    pub fn set_end_user_efficiency(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(GeneralSystemCharacteristic::EndUserEfficiency, degree)
+    }
+
+    /// Get the adjustment factors's processing complexity degree.
+    pub fn processing_complexity(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::ProcessingComplexity)
+    }
+
+    /// Set the adjustment factors's processing complexity degree.
+    pub fn set_processing_complexity(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(GeneralSystemCharacteristic::ProcessingComplexity, degree)
+    }
+
+    /// Get the adjustment factors's code reuse degree.
+    pub fn code_reuse(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::CodeReuse)
+    }
+
+    /// Set the adjustment factors's code reuse degree.
+    pub fn set_code_reuse(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(GeneralSystemCharacteristic::CodeReuse, degree)
+    }
+
+    /// Get the adjustment factors's implementation facility degree.
+    pub fn implementation_facility(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::ImplementationFacility)
+    }
+
+    /// Set the adjustment factors's implementation facility degree.
+    pub fn set_implementation_facility(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(GeneralSystemCharacteristic::ImplementationFacility, degree)
+    }
+
+    /// Get the adjustment factors's operation facility degree.
+    pub fn operation_facility(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::OperationFacility)
+    }
+
+    /// Set the adjustment factors's operation facility degree.
+    pub fn set_operation_facility(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(GeneralSystemCharacteristic::OperationFacility, degree)
+    }
+
+    /// Get the adjustment factors's maintenance facility degree.
+    pub fn maintenance_facility(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::MaintenanceFacility)
+    }
+
+    /// Set the adjustment factors's maintenance facility degree.
+    pub fn set_maintenance_facility(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(GeneralSystemCharacteristic::MaintenanceFacility, degree)
+    }
+
+    /// Get the adjustment factors's operation in multiple locations degree.
+    pub fn operation_in_multiple_locations(&self) -> u8 {
+        self.degree(GeneralSystemCharacteristic::OperationInMultipleLocations)
+    }
+
+    /// Set the adjustment factors's operation in multiple locations degree.
+    pub fn set_operation_in_multiple_locations(&mut self, degree: u8) -> &mut Self {
+        self.set_degree(
+            GeneralSystemCharacteristic::OperationInMultipleLocations,
+            degree,
+        )
     }
 }
 
@@ -66,4 +220,40 @@ mod tests {
 
         assert_eq!(af.sum(), 105);
     }
+
+    #[test]
+    fn should_access_characteristics_by_name() {
+        let mut af = AdjustmentFactors::new([0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0]);
+
+        assert_eq!(af.end_user_efficiency(), 4);
+        assert_eq!(af.degree(GeneralSystemCharacteristic::EndUserEfficiency), 4);
+
+        af.set_teleprocessing(3).set_justification(
+            GeneralSystemCharacteristic::Teleprocessing,
+            "Online front end over a single protocol",
+        );
+
+        let rating = af.rating(GeneralSystemCharacteristic::Teleprocessing);
+
+        assert_eq!(rating.degree(), 3);
+        assert_eq!(
+            rating.justification(),
+            Some("Online front end over a single protocol")
+        );
+        assert_eq!(af.sum(), 7);
+    }
+
+    #[test]
+    fn should_report_out_of_range_ratings() {
+        let mut af = AdjustmentFactors::new([5; 14]);
+
+        assert!(af.out_of_range().is_empty());
+
+        af.set_code_reuse(6);
+
+        assert_eq!(
+            af.out_of_range(),
+            vec![GeneralSystemCharacteristic::CodeReuse]
+        );
+    }
 }
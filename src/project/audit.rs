@@ -0,0 +1,371 @@
+use std::error::Error;
+use std::fmt;
+use std::time::SystemTime;
+
+use super::adjustment_factors::AdjustmentFactors;
+use super::basic_functional_component::{
+    BasicFunctionalComponent, ElementaryDataReferenced, FileRegistry,
+};
+use super::weighting_factors::WeightingFactors;
+use super::Project;
+
+/// Mutation applied to a project.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub enum Mutation {
+    AddBfc(BasicFunctionalComponent),
+    SetEdr {
+        index: usize,
+        edr: ElementaryDataReferenced,
+    },
+    SetFileRegistry {
+        index: usize,
+        file_registry: FileRegistry,
+    },
+    SetAdjustmentFactors(Box<AdjustmentFactors>),
+    SetWeightingFactors(WeightingFactors),
+    SetCostPerHour(f32),
+}
+
+impl Mutation {
+    /// Apply the mutation to a project, failing without changing it when the
+    /// mutation refers to a component the project does not have.
+    pub fn apply(&self, project: &mut Project) -> Result<(), AuditError> {
+        let check = |index: usize| {
+            if index < project.basic_functional_components().len() {
+                Ok(index)
+            } else {
+                Err(AuditError::new(index))
+            }
+        };
+
+        match self {
+            Mutation::AddBfc(bfc) => project.add_bfc(bfc.clone()),
+            Mutation::SetEdr { index, edr } => project.set_bfc_edr(check(*index)?, edr.clone()),
+            Mutation::SetFileRegistry {
+                index,
+                file_registry,
+            } => project.set_bfc_file_registry(check(*index)?, file_registry.clone()),
+            Mutation::SetAdjustmentFactors(af) => project.set_adjustment_factors(*af.clone()),
+            Mutation::SetWeightingFactors(wf) => project.set_weighting_factors(wf.clone()),
+            Mutation::SetCostPerHour(cph) => project.set_cost_per_hour(*cph),
+        };
+
+        Ok(())
+    }
+}
+
+/// Error raised when a mutation refers to a component that does not exist.
+#[derive(PartialEq, Debug, Clone)]
+pub struct AuditError {
+    index: usize,
+}
+
+impl AuditError {
+    pub fn new(index: usize) -> Self {
+        Self { index }
+    }
+
+    /// Get the index of the missing component.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for AuditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no component at index {}", self.index)
+    }
+}
+
+impl Error for AuditError {}
+
+/// Mutation recorded with who made it, when and why.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct Event {
+    author: String,
+    timestamp: SystemTime,
+    reason: String,
+    mutation: Mutation,
+}
+
+impl Event {
+    /// Create an event timestamped now.
+    pub fn new(author: impl Into<String>, reason: impl Into<String>, mutation: Mutation) -> Self {
+        Self::with_timestamp(author, reason, mutation, SystemTime::now())
+    }
+
+    pub fn with_timestamp(
+        author: impl Into<String>,
+        reason: impl Into<String>,
+        mutation: Mutation,
+        timestamp: SystemTime,
+    ) -> Self {
+        Self {
+            author: author.into(),
+            timestamp,
+            reason: reason.into(),
+            mutation,
+        }
+    }
+
+    /// Get a reference to the event's author.
+    pub fn author(&self) -> &str {
+        &self.author
+    }
+
+    /// Get a reference to the event's timestamp.
+    pub fn timestamp(&self) -> &SystemTime {
+        &self.timestamp
+    }
+
+    /// Get a reference to the event's reason.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Get a reference to the event's mutation.
+    pub fn mutation(&self) -> &Mutation {
+        &self.mutation
+    }
+}
+
+/// Project whose every mutation is recorded in an event log supporting
+/// undo and redo.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone)]
+pub struct AuditedProject {
+    base: Project,
+    events: Vec<Event>,
+    applied: usize,
+    project: Project,
+}
+
+impl AuditedProject {
+    /// Start an audit log from an empty project.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start an audit log from an existing baseline.
+    pub fn from_baseline(base: Project) -> Self {
+        Self {
+            project: base.clone(),
+            base,
+            events: Vec::new(),
+            applied: 0,
+        }
+    }
+
+    /// Rebuild a project by replaying events over a baseline.
+    pub fn from_events(base: Project, events: Vec<Event>) -> Result<Self, AuditError> {
+        let mut audited = Self::from_baseline(base);
+
+        audited.applied = events.len();
+        audited.events = events;
+        audited.project = audited.rebuild()?;
+
+        Ok(audited)
+    }
+
+    /// Apply and record an event, discarding any undone events. An event
+    /// that can not be applied is not recorded.
+    pub fn record(&mut self, event: Event) -> Result<&mut Self, AuditError> {
+        event.mutation().apply(&mut self.project)?;
+        self.events.truncate(self.applied);
+        self.events.push(event);
+        self.applied += 1;
+
+        Ok(self)
+    }
+
+    /// Undo the last applied event. Returns `false` when there is nothing to undo.
+    pub fn undo(&mut self) -> Result<bool, AuditError> {
+        if self.applied == 0 {
+            return Ok(false);
+        }
+
+        self.applied -= 1;
+        self.project = self.rebuild()?;
+
+        Ok(true)
+    }
+
+    /// Redo the last undone event. Returns `false` when there is nothing to redo.
+    pub fn redo(&mut self) -> Result<bool, AuditError> {
+        match self.events.get(self.applied) {
+            Some(event) => {
+                event.mutation().apply(&mut self.project)?;
+                self.applied += 1;
+
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Replay the applied events over the baseline.
+    pub fn rebuild(&self) -> Result<Project, AuditError> {
+        let mut project = self.base.clone();
+
+        for event in self.applied_events() {
+            event.mutation().apply(&mut project)?;
+        }
+
+        Ok(project)
+    }
+
+    /// Get a reference to the current state of the project.
+    pub fn project(&self) -> &Project {
+        &self.project
+    }
+
+    /// Events currently applied to the project, oldest first.
+    pub fn applied_events(&self) -> &[Event] {
+        &self.events[..self.applied.min(self.events.len())]
+    }
+
+    /// Every recorded event, including the undone ones that can be redone.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::basic_functional_component::FunctionalClassification;
+
+    fn weighting_factors() -> WeightingFactors {
+        let mut wf = WeightingFactors::new();
+
+        wf.set_referenced_logical_file([7, 10, 15])
+            .set_external_input([3, 4, 6]);
+
+        wf
+    }
+
+    fn session() -> AuditedProject {
+        let mut audited = AuditedProject::new();
+
+        audited
+            .record(Event::new(
+                "ana",
+                "Client weights",
+                Mutation::SetWeightingFactors(weighting_factors()),
+            ))
+            .unwrap()
+            .record(Event::new(
+                "ana",
+                "Customer data",
+                Mutation::AddBfc(BasicFunctionalComponent::new(
+                    "Customer",
+                    FunctionalClassification::InternalLogicalFile,
+                )),
+            ))
+            .unwrap()
+            .record(Event::new(
+                "bruno",
+                "Reviewed DETs",
+                Mutation::SetEdr {
+                    index: 0,
+                    edr: ElementaryDataReferenced::new(25, 0),
+                },
+            ))
+            .unwrap()
+            .record(Event::new(
+                "bruno",
+                "Reviewed RETs",
+                Mutation::SetFileRegistry {
+                    index: 0,
+                    file_registry: FileRegistry::new(2, 0),
+                },
+            ))
+            .unwrap();
+
+        audited
+    }
+
+    #[test]
+    fn should_record_events() {
+        let audited = session();
+
+        assert_eq!(audited.events().len(), 4);
+        assert_eq!(audited.events()[2].author(), "bruno");
+        assert_eq!(audited.events()[2].reason(), "Reviewed DETs");
+        assert_eq!(10f32, audited.project().total_function_point_not_adjusted());
+    }
+
+    #[test]
+    fn should_undo_and_redo() {
+        let mut audited = session();
+
+        assert!(audited.undo().unwrap());
+        assert_eq!(15f32, audited.project().total_function_point_not_adjusted());
+        assert_eq!(audited.applied_events().len(), 3);
+
+        assert!(audited.redo().unwrap());
+        assert!(!audited.redo().unwrap());
+        assert_eq!(10f32, audited.project().total_function_point_not_adjusted());
+
+        while audited.undo().unwrap() {}
+
+        assert!(audited.project().basic_functional_components().is_empty());
+
+        audited.redo().unwrap();
+        audited
+            .record(Event::new("carla", "Rate", Mutation::SetCostPerHour(50f32)))
+            .unwrap();
+
+        assert_eq!(audited.events().len(), 2);
+        assert!(!audited.redo().unwrap());
+    }
+
+    #[test]
+    fn should_rebuild_from_events() {
+        let audited = session();
+        let rebuilt =
+            AuditedProject::from_events(Project::new(), audited.events().to_vec()).unwrap();
+
+        assert_eq!(
+            rebuilt.project().basic_functional_components(),
+            audited.project().basic_functional_components()
+        );
+        assert_eq!(
+            rebuilt.project().total_function_point_not_adjusted(),
+            audited.project().total_function_point_not_adjusted()
+        );
+    }
+
+    #[test]
+    fn should_reject_events_on_missing_components() {
+        let mut audited = session();
+        let invalid = Event::new(
+            "carla",
+            "Typo",
+            Mutation::SetEdr {
+                index: 3,
+                edr: ElementaryDataReferenced::new(1, 0),
+            },
+        );
+
+        assert_eq!(
+            audited.record(invalid.clone()).unwrap_err(),
+            AuditError::new(3)
+        );
+        assert_eq!(audited.events().len(), 4);
+        assert_eq!(10f32, audited.project().total_function_point_not_adjusted());
+
+        let mut events = audited.events().to_vec();
+
+        events.push(invalid);
+
+        assert_eq!(
+            AuditedProject::from_events(Project::new(), events)
+                .unwrap_err()
+                .to_string(),
+            "no component at index 3"
+        );
+    }
+}
@@ -1,18 +1,100 @@
+use std::fmt;
+
+use super::early_quick::EarlyQuickLevel;
+use super::grouping::GROUP_SEPARATOR;
+use super::planning::Priority;
+use super::progress::DeliveryStatus;
+
 pub use elementary_data_referenced::ElementaryDataReferenced;
 pub use file_registry::FileRegistry;
+pub use traceability::Traceability;
 
 mod elementary_data_referenced;
 mod file_registry;
+mod traceability;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "StoredComponent"))]
+#[cfg_attr(feature = "python", pyo3::pyclass(from_py_object, module = "fpa_rs"))]
 #[derive(PartialEq, Debug, Clone)]
 pub struct BasicFunctionalComponent {
+    id: Option<String>,
     name: String,
     functional_classification: FunctionalClassification,
     elementary_data_referenced: ElementaryDataReferenced,
     file_record: FileRegistry,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     complexity: Complexity,
+    early_quick_level: Option<EarlyQuickLevel>,
+    traceability: Traceability,
+    groups: Vec<String>,
+    release: Option<String>,
+    priority: Option<Priority>,
+    status: DeliveryStatus,
+    planned_period: Option<u32>,
+}
+
+/// Serialized form of a component, whose complexity is derived again on load.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct StoredComponent {
+    #[serde(default)]
+    id: Option<String>,
+    name: String,
+    functional_classification: FunctionalClassification,
+    elementary_data_referenced: ElementaryDataReferenced,
+    file_record: FileRegistry,
+    early_quick_level: Option<EarlyQuickLevel>,
+    #[serde(default)]
+    traceability: Traceability,
+    #[serde(default)]
+    groups: Vec<String>,
+    #[serde(default)]
+    release: Option<String>,
+    #[serde(default)]
+    priority: Option<Priority>,
+    #[serde(default)]
+    status: DeliveryStatus,
+    #[serde(default)]
+    planned_period: Option<u32>,
+}
+
+#[cfg(feature = "serde")]
+impl From<StoredComponent> for BasicFunctionalComponent {
+    fn from(stored: StoredComponent) -> Self {
+        let mut bfc = BasicFunctionalComponent {
+            id: stored.id,
+            name: stored.name,
+            functional_classification: stored.functional_classification,
+            elementary_data_referenced: stored.elementary_data_referenced,
+            file_record: stored.file_record,
+            complexity: Complexity::Simple,
+            early_quick_level: stored.early_quick_level,
+            traceability: stored.traceability,
+            groups: stored.groups,
+            release: stored.release,
+            priority: stored.priority,
+            status: stored.status,
+            planned_period: stored.planned_period,
+        };
+
+        bfc.compute_complexity();
+
+        bfc
+    }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(
+        from_py_object,
+        eq,
+        eq_int,
+        rename_all = "SCREAMING_SNAKE_CASE",
+        module = "fpa_rs"
+    )
+)]
 #[derive(PartialOrd, PartialEq, Debug, Clone)]
 pub enum Complexity {
     Simple,
@@ -20,6 +102,17 @@ pub enum Complexity {
     Complex,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(
+        from_py_object,
+        eq,
+        eq_int,
+        rename_all = "SCREAMING_SNAKE_CASE",
+        module = "fpa_rs"
+    )
+)]
 #[derive(PartialEq, Debug, Clone)]
 pub enum FunctionalClassification {
     ExternalInput,
@@ -29,14 +122,71 @@ pub enum FunctionalClassification {
     ExternalInterfaceFile,
 }
 
+impl fmt::Display for Complexity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Complexity::Simple => write!(f, "Simple"),
+            Complexity::Middle => write!(f, "Middle"),
+            Complexity::Complex => write!(f, "Complex"),
+        }
+    }
+}
+
+impl FunctionalClassification {
+    /// Usual abbreviation of the classification (EI, EO, EQ, ILF or EIF).
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            FunctionalClassification::ExternalInput => "EI",
+            FunctionalClassification::ExternalOutput => "EO",
+            FunctionalClassification::ExternalQuery => "EQ",
+            FunctionalClassification::InternalLogicalFile => "ILF",
+            FunctionalClassification::ExternalInterfaceFile => "EIF",
+        }
+    }
+
+    /// Classification with the given abbreviation.
+    pub fn from_abbreviation(abbreviation: &str) -> Option<Self> {
+        match abbreviation {
+            "EI" => Some(FunctionalClassification::ExternalInput),
+            "EO" => Some(FunctionalClassification::ExternalOutput),
+            "EQ" => Some(FunctionalClassification::ExternalQuery),
+            "ILF" => Some(FunctionalClassification::InternalLogicalFile),
+            "EIF" => Some(FunctionalClassification::ExternalInterfaceFile),
+            _ => None,
+        }
+    }
+
+    /// Whether the classification is a transactional function (EI, EO or EQ).
+    pub fn is_transactional(&self) -> bool {
+        !self.is_data_function()
+    }
+
+    /// Whether the classification is a data function (ILF or EIF).
+    pub fn is_data_function(&self) -> bool {
+        matches!(
+            self,
+            FunctionalClassification::InternalLogicalFile
+                | FunctionalClassification::ExternalInterfaceFile
+        )
+    }
+}
+
 impl BasicFunctionalComponent {
-    pub fn new(name: &'static str, fc: FunctionalClassification) -> Self {
+    pub fn new(name: impl Into<String>, fc: FunctionalClassification) -> Self {
         let mut bfc = BasicFunctionalComponent {
-            name: name.to_string(),
+            id: None,
+            name: name.into(),
             functional_classification: fc,
             elementary_data_referenced: ElementaryDataReferenced::default(),
             file_record: FileRegistry::default(),
             complexity: Complexity::Simple,
+            early_quick_level: None,
+            traceability: Traceability::default(),
+            groups: Vec::new(),
+            release: None,
+            priority: None,
+            status: DeliveryStatus::NotStarted,
+            planned_period: None,
         };
 
         bfc.compute_complexity();
@@ -44,6 +194,13 @@ impl BasicFunctionalComponent {
         bfc
     }
 
+    /// Set the identity used to match the component across revisions of a count.
+    pub fn set_id(&mut self, id: impl Into<String>) -> &mut Self {
+        self.id = Some(id.into());
+
+        self
+    }
+
     /// Set the Elementary Data Referenced.
     pub fn set_edr(&mut self, red: ElementaryDataReferenced) -> &mut Self {
         self.elementary_data_referenced = red;
@@ -60,6 +217,84 @@ impl BasicFunctionalComponent {
         self
     }
 
+    /// Set the Early & Quick FP level, overriding the one derived from the classification.
+    pub fn set_early_quick_level(&mut self, level: EarlyQuickLevel) -> &mut Self {
+        self.early_quick_level = Some(level);
+
+        self
+    }
+
+    /// Link the component to a requirement or user story ID.
+    pub fn add_requirement(&mut self, requirement: impl Into<String>) -> &mut Self {
+        self.traceability.add_requirement(requirement);
+
+        self
+    }
+
+    /// Add a source document reference to the component.
+    pub fn add_source_reference(&mut self, reference: impl Into<String>) -> &mut Self {
+        self.traceability.add_source_reference(reference);
+
+        self
+    }
+
+    /// Add a counting assumption to the component.
+    pub fn add_assumption(&mut self, assumption: impl Into<String>) -> &mut Self {
+        self.traceability.add_assumption(assumption);
+
+        self
+    }
+
+    /// Tag the component with a module or subsystem label.
+    ///
+    /// Labels are hierarchical, separated by `/`: a component tagged
+    /// "billing/invoices" also belongs to "billing".
+    pub fn add_group(&mut self, label: impl Into<String>) -> &mut Self {
+        let label = label.into();
+
+        if !self.groups.contains(&label) {
+            self.groups.push(label);
+        }
+
+        self
+    }
+
+    /// Whether the component belongs to a group or to one of its subgroups.
+    pub fn belongs_to(&self, label: &str) -> bool {
+        self.groups.iter().any(|group| {
+            group == label
+                || (group.starts_with(label) && group[label.len()..].starts_with(GROUP_SEPARATOR))
+        })
+    }
+
+    /// Assign the component to a release or iteration.
+    pub fn set_release(&mut self, release: impl Into<String>) -> &mut Self {
+        self.release = Some(release.into());
+
+        self
+    }
+
+    /// Set the component's MoSCoW priority.
+    pub fn set_priority(&mut self, priority: Priority) -> &mut Self {
+        self.priority = Some(priority);
+
+        self
+    }
+
+    /// Set the component's delivery status.
+    pub fn set_status(&mut self, status: DeliveryStatus) -> &mut Self {
+        self.status = status;
+
+        self
+    }
+
+    /// Set the period in which the component is planned to be delivered.
+    pub fn set_planned_period(&mut self, period: u32) -> &mut Self {
+        self.planned_period = Some(period);
+
+        self
+    }
+
     fn compute_complexity(&mut self) {
         self.complexity = match self.functional_classification {
             FunctionalClassification::ExternalInterfaceFile
@@ -70,6 +305,8 @@ impl BasicFunctionalComponent {
         };
     }
 
+    // The arms follow the columns of the IFPUG complexity tables.
+    #[allow(clippy::manual_range_patterns)]
     fn logic_files_complexity(&self) -> Complexity {
         match self.file_record.total() {
             1 => match self.elementary_data_referenced.total() {
@@ -88,6 +325,7 @@ impl BasicFunctionalComponent {
         }
     }
 
+    #[allow(clippy::manual_range_patterns)]
     fn external_input_complexity(&self) -> Complexity {
         match self.file_record.total() {
             0..=1 => match self.elementary_data_referenced.total() {
@@ -106,6 +344,7 @@ impl BasicFunctionalComponent {
         }
     }
 
+    #[allow(clippy::manual_range_patterns)]
     fn external_query_complexity(&self) -> Complexity {
         match self.file_record.total() {
             0..=1 => match self.elementary_data_referenced.total() {
@@ -124,6 +363,7 @@ impl BasicFunctionalComponent {
         }
     }
 
+    #[allow(clippy::manual_range_patterns)]
     fn external_output_complexity(&self) -> Complexity {
         let complexity_output = match self.file_record.output {
             0..=1 => match self.elementary_data_referenced.total() {
@@ -164,6 +404,11 @@ impl BasicFunctionalComponent {
         }
     }
 
+    /// Get a reference to the basic functional component's identity.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
     /// Get a reference to the basic functional component's name.
     pub fn name(&self) -> &String {
         &self.name
@@ -188,6 +433,42 @@ impl BasicFunctionalComponent {
     pub fn complexity(&self) -> &Complexity {
         &self.complexity
     }
+
+    /// Get a reference to the basic functional component's traceability.
+    pub fn traceability(&self) -> &Traceability {
+        &self.traceability
+    }
+
+    /// Get a reference to the basic functional component's group labels.
+    pub fn groups(&self) -> &[String] {
+        &self.groups
+    }
+
+    /// Get a reference to the basic functional component's release.
+    pub fn release(&self) -> Option<&str> {
+        self.release.as_deref()
+    }
+
+    /// Get a reference to the basic functional component's priority.
+    pub fn priority(&self) -> Option<&Priority> {
+        self.priority.as_ref()
+    }
+
+    /// Get a reference to the basic functional component's delivery status.
+    pub fn status(&self) -> &DeliveryStatus {
+        &self.status
+    }
+
+    /// Get the basic functional component's planned delivery period.
+    pub fn planned_period(&self) -> Option<u32> {
+        self.planned_period
+    }
+
+    /// Get the basic functional component's Early & Quick FP level.
+    pub fn early_quick_level(&self) -> EarlyQuickLevel {
+        self.early_quick_level
+            .unwrap_or_else(|| EarlyQuickLevel::from(&self.functional_classification))
+    }
 }
 
 #[cfg(test)]
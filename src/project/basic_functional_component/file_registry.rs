@@ -1,3 +1,10 @@
+/// FTRs of a transaction or RETs of a data function, split into the input
+/// and output sides.
+///
+/// Only an EO tells the sides apart: its complexity is the higher of the EI
+/// table over the input FTRs and the EO table over the output ones. Other
+/// components are rated on the total.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, PartialEq, Debug, Default)]
 pub struct FileRegistry {
     pub input: u32,
@@ -0,0 +1,79 @@
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Traceability {
+    requirements: Vec<String>,
+    source_references: Vec<String>,
+    assumptions: Vec<String>,
+}
+
+impl Traceability {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get a reference to the traceability's requirement or user story IDs.
+    pub fn requirements(&self) -> &[String] {
+        &self.requirements
+    }
+
+    /// Get a reference to the traceability's source document references.
+    pub fn source_references(&self) -> &[String] {
+        &self.source_references
+    }
+
+    /// Get a reference to the traceability's assumptions.
+    pub fn assumptions(&self) -> &[String] {
+        &self.assumptions
+    }
+
+    /// Whether the traceability links to a requirement.
+    pub fn traces(&self, requirement: &str) -> bool {
+        self.requirements.iter().any(|r| r == requirement)
+    }
+
+    /// Add a requirement or user story ID, ignoring duplicates.
+    pub fn add_requirement(&mut self, requirement: impl Into<String>) -> &mut Self {
+        let requirement = requirement.into();
+
+        if !self.traces(&requirement) {
+            self.requirements.push(requirement);
+        }
+
+        self
+    }
+
+    /// Add a source document reference.
+    pub fn add_source_reference(&mut self, reference: impl Into<String>) -> &mut Self {
+        self.source_references.push(reference.into());
+        self
+    }
+
+    /// Add a free-form assumption.
+    pub fn add_assumption(&mut self, assumption: impl Into<String>) -> &mut Self {
+        self.assumptions.push(assumption.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_ignore_duplicated_requirements() {
+        let mut traceability = Traceability::new();
+
+        traceability
+            .add_requirement("US-12")
+            .add_requirement("US-12")
+            .add_requirement("REQ-3")
+            .add_source_reference("SRS v1.2, section 4")
+            .add_assumption("Address is a single DET");
+
+        assert_eq!(traceability.requirements(), &["US-12", "REQ-3"]);
+        assert!(traceability.traces("REQ-3"));
+        assert!(!traceability.traces("REQ-4"));
+        assert_eq!(traceability.source_references().len(), 1);
+        assert_eq!(traceability.assumptions().len(), 1);
+    }
+}
@@ -0,0 +1,517 @@
+use std::fmt;
+
+use super::basic_functional_component::{
+    BasicFunctionalComponent, Complexity, FunctionalClassification,
+};
+use super::general_system_characteristics::GeneralSystemCharacteristic;
+use super::Project;
+
+/// Value before and after a revision.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct Change<T> {
+    pub before: T,
+    pub after: T,
+}
+
+impl<T: PartialEq> Change<T> {
+    pub fn new(before: T, after: T) -> Self {
+        Self { before, after }
+    }
+
+    /// Whether the value changed.
+    pub fn is_changed(&self) -> bool {
+        self.before != self.after
+    }
+}
+
+impl Change<f32> {
+    /// Difference between the revised and the original value.
+    pub fn delta(&self) -> f32 {
+        self.after - self.before
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct ComponentSnapshot {
+    pub name: String,
+    pub functional_classification: FunctionalClassification,
+    pub weight: u32,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct ComponentModification {
+    pub name: Change<String>,
+    pub functional_classification: Change<FunctionalClassification>,
+    pub elementary_data_referenced: Change<u32>,
+    pub file_references: Change<u32>,
+    pub complexity: Change<Complexity>,
+    pub weight: Change<u32>,
+}
+
+impl ComponentModification {
+    /// Whether the component moved to another functional classification.
+    pub fn is_reclassified(&self) -> bool {
+        self.functional_classification.is_changed()
+    }
+
+    fn is_changed(&self) -> bool {
+        self.name.is_changed()
+            || self.functional_classification.is_changed()
+            || self.elementary_data_referenced.is_changed()
+            || self.file_references.is_changed()
+            || self.complexity.is_changed()
+            || self.weight.is_changed()
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub enum ComponentChange {
+    Added(ComponentSnapshot),
+    Removed(ComponentSnapshot),
+    Modified(ComponentModification),
+}
+
+/// Structural differences between two counts of a project.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct ProjectDiff {
+    pub components: Vec<ComponentChange>,
+    pub ratings: Vec<(GeneralSystemCharacteristic, Change<u8>)>,
+    pub final_adjustment_factor: Change<f32>,
+    pub function_points_not_adjusted: Change<f32>,
+    pub final_adjusted_function_points: Change<f32>,
+    pub total_cost: Change<f32>,
+}
+
+impl ProjectDiff {
+    /// Compare an original count with its revision.
+    ///
+    /// Components are matched by identity when both have one, and by name
+    /// otherwise. Identities are matched first, so a component without one
+    /// never takes the counterpart of another by name.
+    pub fn new(original: &Project, revised: &Project) -> Self {
+        let old = original.basic_functional_components();
+        let new = revised.basic_functional_components();
+        let mut matched = vec![false; old.len()];
+        let mut positions = vec![None; new.len()];
+        let mut components = Vec::new();
+
+        for by_name in [false, true] {
+            for (position, bfc) in positions.iter_mut().zip(new) {
+                if position.is_some() {
+                    continue;
+                }
+
+                *position = (0..old.len()).find(|i| {
+                    !matched[*i]
+                        && match (old[*i].id(), bfc.id()) {
+                            (Some(old_id), Some(id)) => old_id == id,
+                            _ => by_name && old[*i].name() == bfc.name(),
+                        }
+                });
+
+                if let Some(i) = *position {
+                    matched[i] = true;
+                }
+            }
+        }
+
+        for (bfc, position) in new.iter().zip(positions) {
+            match position {
+                Some(i) => {
+                    let modification = ComponentModification {
+                        name: Change::new(old[i].name().clone(), bfc.name().clone()),
+                        functional_classification: Change::new(
+                            old[i].functional_classification().clone(),
+                            bfc.functional_classification().clone(),
+                        ),
+                        elementary_data_referenced: Change::new(
+                            old[i].elementary_data_referenced().total(),
+                            bfc.elementary_data_referenced().total(),
+                        ),
+                        file_references: Change::new(
+                            old[i].file_record().total(),
+                            bfc.file_record().total(),
+                        ),
+                        complexity: Change::new(
+                            old[i].complexity().clone(),
+                            bfc.complexity().clone(),
+                        ),
+                        weight: Change::new(
+                            original.weighting_factors().weight(&old[i]),
+                            revised.weighting_factors().weight(bfc),
+                        ),
+                    };
+
+                    if modification.is_changed() {
+                        components.push(ComponentChange::Modified(modification));
+                    }
+                }
+                None => components.push(ComponentChange::Added(snapshot(revised, bfc))),
+            }
+        }
+
+        for (i, bfc) in old.iter().enumerate() {
+            if !matched[i] {
+                components.push(ComponentChange::Removed(snapshot(original, bfc)));
+            }
+        }
+
+        let ratings = GeneralSystemCharacteristic::ALL
+            .iter()
+            .map(|gsc| {
+                (
+                    *gsc,
+                    Change::new(
+                        original.adjustment_factors().degree(*gsc),
+                        revised.adjustment_factors().degree(*gsc),
+                    ),
+                )
+            })
+            .filter(|(_, change)| change.is_changed())
+            .collect();
+
+        ProjectDiff {
+            components,
+            ratings,
+            final_adjustment_factor: Change::new(
+                original.final_adjustment_factor(),
+                revised.final_adjustment_factor(),
+            ),
+            function_points_not_adjusted: Change::new(
+                original.total_function_point_not_adjusted(),
+                revised.total_function_point_not_adjusted(),
+            ),
+            final_adjusted_function_points: Change::new(
+                original.final_adjusted_function_points(),
+                revised.final_adjusted_function_points(),
+            ),
+            total_cost: Change::new(original.total_cost(), revised.total_cost()),
+        }
+    }
+}
+
+fn snapshot(project: &Project, bfc: &BasicFunctionalComponent) -> ComponentSnapshot {
+    ComponentSnapshot {
+        name: bfc.name().clone(),
+        functional_classification: bfc.functional_classification().clone(),
+        weight: project.weighting_factors().weight(bfc),
+    }
+}
+
+impl fmt::Display for ProjectDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Components")?;
+
+        if self.components.is_empty() {
+            writeln!(f, "  No changes")?;
+        }
+
+        for change in &self.components {
+            match change {
+                ComponentChange::Added(c) => writeln!(
+                    f,
+                    "  + {} {} ({} FP)",
+                    c.functional_classification.abbreviation(),
+                    c.name,
+                    c.weight
+                )?,
+                ComponentChange::Removed(c) => writeln!(
+                    f,
+                    "  - {} {} ({} FP)",
+                    c.functional_classification.abbreviation(),
+                    c.name,
+                    c.weight
+                )?,
+                ComponentChange::Modified(m) => {
+                    let mut details = Vec::new();
+
+                    if m.name.is_changed() {
+                        details.push(format!("renamed from {}", m.name.before));
+                    }
+
+                    if m.is_reclassified() {
+                        details.push(format!(
+                            "reclassified {} -> {}",
+                            m.functional_classification.before.abbreviation(),
+                            m.functional_classification.after.abbreviation()
+                        ));
+                    }
+
+                    for (label, change) in &[
+                        ("DET", &m.elementary_data_referenced),
+                        ("FTR/RET", &m.file_references),
+                        ("weight", &m.weight),
+                    ] {
+                        if change.is_changed() {
+                            details
+                                .push(format!("{} {} -> {}", label, change.before, change.after));
+                        }
+                    }
+
+                    if m.complexity.is_changed() {
+                        details.push(format!(
+                            "complexity {} -> {}",
+                            m.complexity.before, m.complexity.after
+                        ));
+                    }
+
+                    writeln!(
+                        f,
+                        "  ~ {} {}: {}",
+                        m.functional_classification.after.abbreviation(),
+                        m.name.after,
+                        details.join("; ")
+                    )?;
+                }
+            }
+        }
+
+        if !self.ratings.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "General System Characteristics")?;
+
+            for (gsc, change) in &self.ratings {
+                writeln!(f, "  {}: {} -> {}", gsc.name(), change.before, change.after)?;
+            }
+        }
+
+        writeln!(f)?;
+
+        for (label, change) in &[
+            ("Value adjustment factor", &self.final_adjustment_factor),
+            (
+                "Unadjusted function points",
+                &self.function_points_not_adjusted,
+            ),
+            (
+                "Adjusted function points",
+                &self.final_adjusted_function_points,
+            ),
+            ("Total cost", &self.total_cost),
+        ] {
+            writeln!(
+                f,
+                "{}: {:.2} -> {:.2} ({:+.2})",
+                label,
+                change.before,
+                change.after,
+                change.delta()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::adjustment_factors::AdjustmentFactors;
+    use crate::project::basic_functional_component::{ElementaryDataReferenced, FileRegistry};
+    use crate::project::weighting_factors::WeightingFactors;
+
+    fn component(
+        name: &str,
+        fc: FunctionalClassification,
+        det: u32,
+        ftr: u32,
+    ) -> BasicFunctionalComponent {
+        let mut bfc = BasicFunctionalComponent::new(name, fc);
+
+        bfc.set_edr(ElementaryDataReferenced::new(det, 0))
+            .set_file_registry(FileRegistry::new(ftr, 0));
+
+        bfc
+    }
+
+    fn project(components: Vec<BasicFunctionalComponent>, performance: u8) -> Project {
+        let mut proj = Project::new();
+        let mut wf = WeightingFactors::new();
+        let mut af = AdjustmentFactors::new([3; 14]);
+
+        wf.set_referenced_logical_file([7, 10, 15])
+            .set_external_query([3, 4, 6])
+            .set_external_input([3, 4, 6])
+            .set_external_output([4, 5, 7]);
+        af.set_performance(performance);
+
+        proj.set_weighting_factors(wf)
+            .set_adjustment_factors(af)
+            .set_cost_per_hour(10f32);
+
+        for bfc in components {
+            proj.add_bfc(bfc);
+        }
+
+        proj
+    }
+
+    #[test]
+    fn should_diff_projects() {
+        let mut renamed = component(
+            "Client",
+            FunctionalClassification::InternalLogicalFile,
+            25,
+            2,
+        );
+
+        renamed.set_id("ILF-1");
+
+        let mut original_customer = component(
+            "Customer",
+            FunctionalClassification::InternalLogicalFile,
+            10,
+            1,
+        );
+
+        original_customer.set_id("ILF-1");
+
+        let original = project(
+            vec![
+                original_customer,
+                component(
+                    "List customers",
+                    FunctionalClassification::ExternalQuery,
+                    4,
+                    1,
+                ),
+                component("Export", FunctionalClassification::ExternalQuery, 4, 1),
+            ],
+            3,
+        );
+        let revised = project(
+            vec![
+                renamed,
+                component(
+                    "List customers",
+                    FunctionalClassification::ExternalQuery,
+                    4,
+                    1,
+                ),
+                component("Export", FunctionalClassification::ExternalOutput, 4, 1),
+                component(
+                    "Create customer",
+                    FunctionalClassification::ExternalInput,
+                    4,
+                    1,
+                ),
+            ],
+            5,
+        );
+
+        let diff = ProjectDiff::new(&original, &revised);
+
+        assert_eq!(diff.components.len(), 3);
+
+        match &diff.components[0] {
+            ComponentChange::Modified(m) => {
+                assert_eq!(
+                    m.name,
+                    Change::new("Customer".to_string(), "Client".to_string())
+                );
+                assert_eq!(m.elementary_data_referenced, Change::new(10, 25));
+                assert_eq!(
+                    m.complexity,
+                    Change::new(Complexity::Simple, Complexity::Middle)
+                );
+                assert_eq!(m.weight, Change::new(7, 10));
+                assert!(!m.is_reclassified());
+            }
+            change => panic!("unexpected change {:?}", change),
+        }
+
+        match &diff.components[1] {
+            ComponentChange::Modified(m) => assert!(m.is_reclassified()),
+            change => panic!("unexpected change {:?}", change),
+        }
+
+        assert_eq!(
+            diff.components[2],
+            ComponentChange::Added(ComponentSnapshot {
+                name: "Create customer".to_string(),
+                functional_classification: FunctionalClassification::ExternalInput,
+                weight: 3,
+            })
+        );
+        assert_eq!(
+            diff.ratings,
+            vec![(GeneralSystemCharacteristic::Performance, Change::new(3, 5))]
+        );
+        assert_eq!(
+            "7.00",
+            format!("{:.2}", diff.function_points_not_adjusted.delta())
+        );
+    }
+
+    #[test]
+    fn should_match_identities_before_names() {
+        let mut customer = component(
+            "Customer",
+            FunctionalClassification::InternalLogicalFile,
+            10,
+            1,
+        );
+
+        customer.set_id("ILF-1");
+
+        let mut client = component(
+            "Client",
+            FunctionalClassification::InternalLogicalFile,
+            10,
+            1,
+        );
+
+        client.set_id("ILF-1");
+
+        let original = project(vec![customer], 3);
+        let revised = project(
+            vec![
+                component(
+                    "Customer",
+                    FunctionalClassification::InternalLogicalFile,
+                    10,
+                    1,
+                ),
+                client,
+            ],
+            3,
+        );
+
+        let diff = ProjectDiff::new(&original, &revised);
+
+        assert_eq!(diff.components.len(), 2);
+        assert!(matches!(
+            &diff.components[0],
+            ComponentChange::Added(snapshot) if snapshot.name == "Customer"
+        ));
+        assert!(matches!(
+            &diff.components[1],
+            ComponentChange::Modified(m) if m.name.is_changed()
+        ));
+    }
+
+    #[test]
+    fn should_render_changelog() {
+        let original = project(
+            vec![component(
+                "Report",
+                FunctionalClassification::ExternalOutput,
+                4,
+                1,
+            )],
+            3,
+        );
+        let revised = project(vec![], 3);
+
+        let changelog = ProjectDiff::new(&original, &revised).to_string();
+
+        assert!(changelog.contains("  - EO Report (4 FP)"));
+        assert!(changelog.contains("Unadjusted function points: 4.00 -> 0.00 (-4.00)"));
+        assert!(!changelog.contains("General System Characteristics"));
+    }
+}
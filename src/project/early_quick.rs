@@ -0,0 +1,185 @@
+use std::ops::Add;
+
+use super::basic_functional_component::FunctionalClassification;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum EarlyQuickLevel {
+    GenericInput,
+    GenericOutput,
+    GenericQuery,
+    UnspecifiedGenericProcess,
+    TypicalProcessSmall,
+    TypicalProcessMedium,
+    TypicalProcessLarge,
+    GeneralProcessSmall,
+    GeneralProcessMedium,
+    GeneralProcessLarge,
+    GenericInternalFile,
+    GenericExternalFile,
+    UnspecifiedGenericDataGroup,
+}
+
+impl EarlyQuickLevel {
+    pub const ALL: [EarlyQuickLevel; 13] = [
+        EarlyQuickLevel::GenericInput,
+        EarlyQuickLevel::GenericOutput,
+        EarlyQuickLevel::GenericQuery,
+        EarlyQuickLevel::UnspecifiedGenericProcess,
+        EarlyQuickLevel::TypicalProcessSmall,
+        EarlyQuickLevel::TypicalProcessMedium,
+        EarlyQuickLevel::TypicalProcessLarge,
+        EarlyQuickLevel::GeneralProcessSmall,
+        EarlyQuickLevel::GeneralProcessMedium,
+        EarlyQuickLevel::GeneralProcessLarge,
+        EarlyQuickLevel::GenericInternalFile,
+        EarlyQuickLevel::GenericExternalFile,
+        EarlyQuickLevel::UnspecifiedGenericDataGroup,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl From<&FunctionalClassification> for EarlyQuickLevel {
+    fn from(fc: &FunctionalClassification) -> Self {
+        match fc {
+            FunctionalClassification::ExternalInput => EarlyQuickLevel::GenericInput,
+            FunctionalClassification::ExternalOutput => EarlyQuickLevel::GenericOutput,
+            FunctionalClassification::ExternalQuery => EarlyQuickLevel::GenericQuery,
+            FunctionalClassification::InternalLogicalFile => EarlyQuickLevel::GenericInternalFile,
+            FunctionalClassification::ExternalInterfaceFile => EarlyQuickLevel::GenericExternalFile,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub struct EarlyQuickRange {
+    min: f32,
+    likely: f32,
+    max: f32,
+}
+
+impl EarlyQuickRange {
+    pub fn new(min: f32, likely: f32, max: f32) -> Self {
+        Self { min, likely, max }
+    }
+
+    /// Get the minimum value.
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    /// Get the most likely value.
+    pub fn likely(&self) -> f32 {
+        self.likely
+    }
+
+    /// Get the maximum value.
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+}
+
+impl Add for EarlyQuickRange {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            min: self.min + other.min,
+            likely: self.likely + other.likely,
+            max: self.max + other.max,
+        }
+    }
+}
+
+/// Min/likely/max values of each Early & Quick FP level.
+///
+/// The defaults are the usual reference averages and should be calibrated by
+/// each organisation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EarlyQuickTable {
+    values: [EarlyQuickRange; 13],
+}
+
+impl Default for EarlyQuickTable {
+    fn default() -> Self {
+        let r = EarlyQuickRange::new;
+
+        EarlyQuickTable {
+            values: [
+                r(4.0, 4.2, 4.4),
+                r(4.9, 5.2, 5.4),
+                r(3.7, 3.9, 4.1),
+                r(4.3, 4.6, 4.8),
+                r(14.8, 16.2, 17.6),
+                r(19.5, 23.2, 26.8),
+                r(25.0, 30.0, 35.0),
+                r(20.0, 30.0, 40.0),
+                r(38.0, 56.0, 74.0),
+                r(62.0, 84.0, 106.0),
+                r(7.4, 7.7, 8.1),
+                r(5.2, 5.4, 5.7),
+                r(6.4, 7.0, 7.8),
+            ],
+        }
+    }
+}
+
+impl EarlyQuickTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the range of a level.
+    pub fn range(&self, level: EarlyQuickLevel) -> &EarlyQuickRange {
+        &self.values[level.index()]
+    }
+
+    /// Set the range of a level.
+    pub fn set_range(&mut self, level: EarlyQuickLevel, range: EarlyQuickRange) -> &mut Self {
+        self.values[level.index()] = range;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_derive_level_from_classification() {
+        assert_eq!(
+            EarlyQuickLevel::from(&FunctionalClassification::ExternalQuery),
+            EarlyQuickLevel::GenericQuery
+        );
+        assert_eq!(
+            EarlyQuickLevel::from(&FunctionalClassification::ExternalInterfaceFile),
+            EarlyQuickLevel::GenericExternalFile
+        );
+    }
+
+    #[test]
+    fn should_override_range() {
+        let mut table = EarlyQuickTable::new();
+        let range = EarlyQuickRange::new(1.0, 2.0, 3.0);
+
+        table.set_range(EarlyQuickLevel::TypicalProcessLarge, range);
+
+        assert_eq!(table.range(EarlyQuickLevel::TypicalProcessLarge), &range);
+        assert_eq!(
+            table.range(EarlyQuickLevel::GenericInput),
+            &EarlyQuickRange::new(4.0, 4.2, 4.4)
+        );
+    }
+
+    #[test]
+    fn should_add_ranges() {
+        let total = EarlyQuickRange::new(1.0, 2.0, 3.0) + EarlyQuickRange::new(1.5, 2.5, 3.5);
+
+        assert_eq!(total, EarlyQuickRange::new(2.5, 4.5, 6.5));
+    }
+}
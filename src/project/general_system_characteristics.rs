@@ -0,0 +1,519 @@
+/// The fourteen IFPUG General System Characteristics, in the order used by
+/// `AdjustmentFactors::new`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum GeneralSystemCharacteristic {
+    Teleprocessing,
+    DistributedProcessing,
+    Performance,
+    MachineLoad,
+    TransactionVolume,
+    OnlineDataInput,
+    OnlineUpdates,
+    EndUserEfficiency,
+    ProcessingComplexity,
+    CodeReuse,
+    ImplementationFacility,
+    OperationFacility,
+    MaintenanceFacility,
+    OperationInMultipleLocations,
+}
+
+/// Highest degree of influence a characteristic can be rated with.
+pub const MAX_DEGREE: u8 = 5;
+
+impl GeneralSystemCharacteristic {
+    pub const ALL: [GeneralSystemCharacteristic; 14] = [
+        GeneralSystemCharacteristic::Teleprocessing,
+        GeneralSystemCharacteristic::DistributedProcessing,
+        GeneralSystemCharacteristic::Performance,
+        GeneralSystemCharacteristic::MachineLoad,
+        GeneralSystemCharacteristic::TransactionVolume,
+        GeneralSystemCharacteristic::OnlineDataInput,
+        GeneralSystemCharacteristic::OnlineUpdates,
+        GeneralSystemCharacteristic::EndUserEfficiency,
+        GeneralSystemCharacteristic::ProcessingComplexity,
+        GeneralSystemCharacteristic::CodeReuse,
+        GeneralSystemCharacteristic::ImplementationFacility,
+        GeneralSystemCharacteristic::OperationFacility,
+        GeneralSystemCharacteristic::MaintenanceFacility,
+        GeneralSystemCharacteristic::OperationInMultipleLocations,
+    ];
+
+    /// Position of the characteristic in `AdjustmentFactors::new`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Snake case key of the characteristic, such as `online_data_input`.
+    pub fn key(self) -> &'static str {
+        match self {
+            GeneralSystemCharacteristic::Teleprocessing => "teleprocessing",
+            GeneralSystemCharacteristic::DistributedProcessing => "distributed_processing",
+            GeneralSystemCharacteristic::Performance => "performance",
+            GeneralSystemCharacteristic::MachineLoad => "machine_load",
+            GeneralSystemCharacteristic::TransactionVolume => "transaction_volume",
+            GeneralSystemCharacteristic::OnlineDataInput => "online_data_input",
+            GeneralSystemCharacteristic::OnlineUpdates => "online_updates",
+            GeneralSystemCharacteristic::EndUserEfficiency => "end_user_efficiency",
+            GeneralSystemCharacteristic::ProcessingComplexity => "processing_complexity",
+            GeneralSystemCharacteristic::CodeReuse => "code_reuse",
+            GeneralSystemCharacteristic::ImplementationFacility => "implementation_facility",
+            GeneralSystemCharacteristic::OperationFacility => "operation_facility",
+            GeneralSystemCharacteristic::MaintenanceFacility => "maintenance_facility",
+            GeneralSystemCharacteristic::OperationInMultipleLocations => {
+                "operation_in_multiple_locations"
+            }
+        }
+    }
+
+    /// Characteristic with the given snake case key.
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|gsc| gsc.key() == key)
+    }
+
+    /// Official IFPUG name of the characteristic.
+    pub fn name(self) -> &'static str {
+        match self {
+            GeneralSystemCharacteristic::Teleprocessing => "Data Communications",
+            GeneralSystemCharacteristic::DistributedProcessing => "Distributed Data Processing",
+            GeneralSystemCharacteristic::Performance => "Performance",
+            GeneralSystemCharacteristic::MachineLoad => "Heavily Used Configuration",
+            GeneralSystemCharacteristic::TransactionVolume => "Transaction Rate",
+            GeneralSystemCharacteristic::OnlineDataInput => "Online Data Entry",
+            GeneralSystemCharacteristic::OnlineUpdates => "Online Update",
+            GeneralSystemCharacteristic::EndUserEfficiency => "End-User Efficiency",
+            GeneralSystemCharacteristic::ProcessingComplexity => "Complex Processing",
+            GeneralSystemCharacteristic::CodeReuse => "Reusability",
+            GeneralSystemCharacteristic::ImplementationFacility => "Installation Ease",
+            GeneralSystemCharacteristic::OperationFacility => "Operational Ease",
+            GeneralSystemCharacteristic::MaintenanceFacility => "Facilitate Change",
+            GeneralSystemCharacteristic::OperationInMultipleLocations => "Multiple Sites",
+        }
+    }
+
+    /// IFPUG description of each degree of influence, from 0 to 5.
+    pub fn rating_descriptions(self) -> &'static [&'static str; 6] {
+        match self {
+            GeneralSystemCharacteristic::Teleprocessing => &[
+                "Application is pure batch processing or a standalone PC.",
+                "Application is batch but has remote data entry or remote printing.",
+                "Application is batch but has remote data entry and remote printing.",
+                "Application includes online data collection or a TP front end to a batch process or query system.",
+                "Application is more than a front end, but supports only one type of TP communications protocol.",
+                "Application is more than a front end, and supports more than one type of TP communications protocol.",
+            ],
+            GeneralSystemCharacteristic::DistributedProcessing => &[
+                "Application does not aid the transfer of data or processing function between components of the system.",
+                "Application prepares data for end-user processing on another component of the system.",
+                "Data is prepared for transfer, then is transferred and processed on another component of the system.",
+                "Distributed processing and data transfer are online and in one direction only.",
+                "Distributed processing and data transfer are online and in both directions.",
+                "Processing functions are dynamically performed on the most appropriate component of the system.",
+            ],
+            GeneralSystemCharacteristic::Performance => &[
+                "No special performance requirements were stated by the user.",
+                "Performance and design requirements were stated and reviewed but no special actions were required.",
+                "Response time or throughput is critical during peak hours; processing deadline is for the next business day.",
+                "Response time or throughput is critical during all business hours; deadlines with interfacing systems are constraining.",
+                "Stated performance requirements are stringent enough to require performance analysis tasks in the design phase.",
+                "Performance analysis tools were used in the design, development or implementation phases to meet the requirements.",
+            ],
+            GeneralSystemCharacteristic::MachineLoad => &[
+                "No explicit or implicit operational restrictions are included.",
+                "Operational restrictions exist but are less restrictive than a typical application; no special effort is needed.",
+                "Some security or timing considerations are included.",
+                "Specific processor requirements for a specific piece of the application are included.",
+                "Stated operation restrictions require special constraints on the application in the central or a dedicated processor.",
+                "There are also special constraints on the application in the distributed components of the system.",
+            ],
+            GeneralSystemCharacteristic::TransactionVolume => &[
+                "No peak transaction period is anticipated.",
+                "A monthly, quarterly, seasonal or annual peak transaction period is anticipated.",
+                "A weekly peak transaction period is anticipated.",
+                "A daily peak transaction period is anticipated.",
+                "Stated transaction rates are high enough to require performance analysis tasks in the design phase.",
+                "Stated transaction rates also require performance analysis tools in the design, development or installation phases.",
+            ],
+            GeneralSystemCharacteristic::OnlineDataInput => &[
+                "All transactions are processed in batch mode.",
+                "1% to 7% of transactions are interactive data entry.",
+                "8% to 15% of transactions are interactive data entry.",
+                "16% to 23% of transactions are interactive data entry.",
+                "24% to 30% of transactions are interactive data entry.",
+                "More than 30% of transactions are interactive data entry.",
+            ],
+            GeneralSystemCharacteristic::OnlineUpdates => &[
+                "None.",
+                "Online update of one to three control files is included; volume of updating is low and recovery is easy.",
+                "Online update of four or more control files is included; volume of updating is low and recovery is easy.",
+                "Online update of major internal logical files is included.",
+                "Protection against data loss is also essential and has been specially designed and programmed in the system.",
+                "High volumes also bring cost considerations into recovery; highly automated recovery procedures are included.",
+            ],
+            GeneralSystemCharacteristic::EndUserEfficiency => &[
+                "None of the end-user efficiency items apply.",
+                "One to three of the end-user efficiency items apply.",
+                "Four to five of the end-user efficiency items apply.",
+                "Six or more items apply, but there are no specific user requirements related to efficiency.",
+                "Six or more items apply, and efficiency requirements require design tasks for human factors.",
+                "Six or more items apply, and efficiency requirements require special tools and processes to demonstrate them.",
+            ],
+            GeneralSystemCharacteristic::ProcessingComplexity => &[
+                "None of the complex processing items apply.",
+                "Any one of the complex processing items applies.",
+                "Any two of the complex processing items apply.",
+                "Any three of the complex processing items apply.",
+                "Any four of the complex processing items apply.",
+                "All five of the complex processing items apply.",
+            ],
+            GeneralSystemCharacteristic::CodeReuse => &[
+                "No reusable code.",
+                "Reusable code is used within the application.",
+                "Less than 10% of the application considered more than one user's needs.",
+                "Ten percent or more of the application considered more than one user's needs.",
+                "The application was packaged or documented to ease reuse, and is customised by the user at source code level.",
+                "The application was packaged or documented to ease reuse, and is customised through user parameter maintenance.",
+            ],
+            GeneralSystemCharacteristic::ImplementationFacility => &[
+                "No special considerations were stated by the user, and no special setup is required for installation.",
+                "No special considerations were stated by the user, but special setup is required for installation.",
+                "Conversion and installation requirements were stated and guides provided and tested; conversion impact is not important.",
+                "Conversion and installation requirements were stated and guides provided and tested; conversion impact is important.",
+                "As in 2, and automated conversion and installation tools were provided and tested.",
+                "As in 3, and automated conversion and installation tools were provided and tested.",
+            ],
+            GeneralSystemCharacteristic::OperationFacility => &[
+                "No special operational considerations other than the normal backup procedures were stated by the user.",
+                "Operational ease items worth one point apply.",
+                "Operational ease items worth two points apply.",
+                "Operational ease items worth three points apply.",
+                "Operational ease items worth four points apply.",
+                "The application is designed for unattended operation.",
+            ],
+            GeneralSystemCharacteristic::MaintenanceFacility => &[
+                "None of the facilitate change items apply.",
+                "A total of one facilitate change item applies.",
+                "A total of two facilitate change items apply.",
+                "A total of three facilitate change items apply.",
+                "A total of four facilitate change items apply.",
+                "A total of five or more facilitate change items apply.",
+            ],
+            GeneralSystemCharacteristic::OperationInMultipleLocations => &[
+                "User requirements do not require considering the needs of more than one user or installation site.",
+                "Multiple sites were considered in the design, and the application runs only under identical environments.",
+                "Multiple sites were considered in the design, and the application runs under similar environments.",
+                "Multiple sites were considered in the design, and the application runs under different environments.",
+                "Documentation and a support plan for multiple sites are provided and tested, and the application is as in 1 or 2.",
+                "Documentation and a support plan for multiple sites are provided and tested, and the application is as in 3.",
+            ],
+        }
+    }
+
+    /// Guidance questions answered by `evaluate`.
+    pub fn guidance_questions(self) -> &'static [&'static str] {
+        match self {
+            GeneralSystemCharacteristic::Teleprocessing => &[
+                "Is there remote data entry or remote printing?",
+                "Is there both remote data entry and remote printing?",
+                "Is there online data collection or a TP front end?",
+                "Is the application more than a front end using one TP protocol?",
+                "Does the application support more than one TP protocol?",
+            ],
+            GeneralSystemCharacteristic::DistributedProcessing => &[
+                "Is data prepared for end-user processing on another component?",
+                "Is data transferred and processed on another component?",
+                "Is distributed processing online in one direction?",
+                "Is distributed processing online in both directions?",
+                "Are processing functions dynamically placed on the most appropriate component?",
+            ],
+            GeneralSystemCharacteristic::Performance => &[
+                "Were performance requirements stated and reviewed?",
+                "Is response time or throughput critical during peak hours?",
+                "Is response time or throughput critical during all business hours?",
+                "Do performance requirements demand analysis tasks in design?",
+                "Were performance analysis tools used to meet the requirements?",
+            ],
+            GeneralSystemCharacteristic::MachineLoad => &[
+                "Do operational restrictions exist?",
+                "Are security or timing considerations included?",
+                "Are there specific processor requirements for part of the application?",
+                "Do restrictions require special constraints on a central or dedicated processor?",
+                "Are there special constraints on the distributed components too?",
+            ],
+            GeneralSystemCharacteristic::TransactionVolume => &[
+                "Is a monthly, quarterly, seasonal or annual peak anticipated?",
+                "Is a weekly peak anticipated?",
+                "Is a daily peak anticipated?",
+                "Do transaction rates require performance analysis tasks in design?",
+                "Do transaction rates require performance analysis tools?",
+            ],
+            GeneralSystemCharacteristic::OnlineDataInput => &[
+                "Are at least 1% of transactions interactive data entry?",
+                "Are at least 8% of transactions interactive data entry?",
+                "Are at least 16% of transactions interactive data entry?",
+                "Are at least 24% of transactions interactive data entry?",
+                "Are more than 30% of transactions interactive data entry?",
+            ],
+            GeneralSystemCharacteristic::OnlineUpdates => &[
+                "Are one to three control files updated online?",
+                "Are four or more control files updated online?",
+                "Are major internal logical files updated online?",
+                "Is protection against data loss specially designed?",
+                "Are highly automated recovery procedures included?",
+            ],
+            GeneralSystemCharacteristic::EndUserEfficiency => &[
+                "Navigational aids?",
+                "Menus?",
+                "Online help and documents?",
+                "Automated cursor movement?",
+                "Scrolling?",
+                "Remote printing via online transactions?",
+                "Preassigned function keys?",
+                "Batch jobs submitted from online transactions?",
+                "Cursor selection of screen data?",
+                "Heavy use of reverse video, highlighting or colours?",
+                "Hard-copy user documentation of online transactions?",
+                "Mouse interface?",
+                "Pop-up windows?",
+                "As few screens as possible to accomplish a business function?",
+                "Bilingual support (counts as four items)?",
+                "Multilingual support (counts as six items)?",
+                "Do efficiency requirements demand human factors design tasks?",
+                "Do efficiency requirements demand special tools to demonstrate them?",
+            ],
+            GeneralSystemCharacteristic::ProcessingComplexity => &[
+                "Sensitive control or application-specific security processing?",
+                "Extensive logical processing?",
+                "Extensive mathematical processing?",
+                "Much exception processing resulting in incomplete transactions?",
+                "Complex processing to handle multiple input/output possibilities?",
+            ],
+            GeneralSystemCharacteristic::CodeReuse => &[
+                "Is reusable code used within the application?",
+                "Did part of the application consider more than one user's needs?",
+                "Did 10% or more of the application consider more than one user's needs?",
+                "Was the application packaged for reuse and customised at source level?",
+                "Was the application packaged for reuse and customised by parameters?",
+            ],
+            GeneralSystemCharacteristic::ImplementationFacility => &[
+                "Is special setup required for installation?",
+                "Were conversion and installation guides provided and tested?",
+                "Is the impact of conversion important?",
+                "Were automated conversion and installation tools provided and tested?",
+                "Were automated tools provided and is the impact of conversion important?",
+            ],
+            GeneralSystemCharacteristic::OperationFacility => &[
+                "Start-up, backup and recovery processes requiring operator intervention?",
+                "Start-up, backup and recovery processes without operator intervention (two points)?",
+                "Does the application minimise the need for tape mounts?",
+                "Does the application minimise the need for paper handling?",
+                "Is the application designed for unattended operation?",
+            ],
+            GeneralSystemCharacteristic::MaintenanceFacility => &[
+                "Flexible query and report facility for simple requests?",
+                "Flexible query and report facility for average requests (two items)?",
+                "Flexible query and report facility for complex requests (three items)?",
+                "User-maintained control data taking effect the next business day?",
+                "User-maintained control data taking effect immediately (two items)?",
+            ],
+            GeneralSystemCharacteristic::OperationInMultipleLocations => &[
+                "Were multiple sites with identical environments considered?",
+                "Were multiple sites with similar environments considered?",
+                "Were multiple sites with different environments considered?",
+                "Are multi-site documentation and support plan provided and tested?",
+                "Are they provided and tested for different environments?",
+            ],
+        }
+    }
+
+    /// Turn answers to `guidance_questions` into a rating.
+    ///
+    /// Missing answers count as "no". The questions answered "yes" are kept
+    /// as the rating's justification.
+    pub fn evaluate(self, answers: &[bool]) -> GscRating {
+        let yes = |i: usize| answers.get(i).copied().unwrap_or(false);
+        let points = |weights: &[u8]| -> u8 {
+            weights
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| yes(*i))
+                .map(|(_, weight)| weight)
+                .sum()
+        };
+
+        let degree = match self {
+            GeneralSystemCharacteristic::ProcessingComplexity => points(&[1, 1, 1, 1, 1]),
+            GeneralSystemCharacteristic::MaintenanceFacility => {
+                points(&[1, 2, 3, 1, 2]).min(MAX_DEGREE)
+            }
+            GeneralSystemCharacteristic::OperationFacility => {
+                if yes(4) {
+                    MAX_DEGREE
+                } else {
+                    points(&[1, 2, 1, 1]).min(4)
+                }
+            }
+            GeneralSystemCharacteristic::EndUserEfficiency => {
+                match points(&[1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 4, 6]) {
+                    0 => 0,
+                    1..=3 => 1,
+                    4..=5 => 2,
+                    _ if yes(17) => 5,
+                    _ if yes(16) => 4,
+                    _ => 3,
+                }
+            }
+            _ => (0..MAX_DEGREE as usize)
+                .rev()
+                .find(|i| yes(*i))
+                .map_or(0, |i| i as u8 + 1),
+        };
+
+        let justification = self
+            .guidance_questions()
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| yes(*i))
+            .map(|(_, question)| *question)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        GscRating {
+            degree,
+            justification: if justification.is_empty() {
+                None
+            } else {
+                Some(justification)
+            },
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct GscRating {
+    degree: u8,
+    justification: Option<String>,
+}
+
+impl GscRating {
+    pub fn new(degree: u8) -> Self {
+        Self {
+            degree,
+            justification: None,
+        }
+    }
+
+    /// Create a rating with a justification text.
+    pub fn with_justification(degree: u8, justification: impl Into<String>) -> Self {
+        Self {
+            degree,
+            justification: Some(justification.into()),
+        }
+    }
+
+    /// Get the rating's degree of influence.
+    pub fn degree(&self) -> u8 {
+        self.degree
+    }
+
+    /// Get the rating's justification.
+    pub fn justification(&self) -> Option<&str> {
+        self.justification.as_deref()
+    }
+
+    /// Set the rating's degree of influence.
+    pub fn set_degree(&mut self, degree: u8) -> &mut Self {
+        self.degree = degree;
+        self
+    }
+
+    /// Set the rating's justification.
+    pub fn set_justification(&mut self, justification: impl Into<String>) -> &mut Self {
+        self.justification = Some(justification.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_characteristics_by_key() {
+        for gsc in GeneralSystemCharacteristic::ALL.iter() {
+            assert_eq!(GeneralSystemCharacteristic::from_key(gsc.key()), Some(*gsc));
+        }
+
+        assert_eq!(GeneralSystemCharacteristic::from_key("speed"), None);
+    }
+
+    #[test]
+    fn should_describe_every_characteristic() {
+        for gsc in GeneralSystemCharacteristic::ALL.iter() {
+            assert_eq!(
+                GeneralSystemCharacteristic::ALL[gsc.index()],
+                *gsc,
+                "{}",
+                gsc.name()
+            );
+            assert!(gsc.rating_descriptions().iter().all(|d| !d.is_empty()));
+            assert!(gsc.guidance_questions().len() >= MAX_DEGREE as usize);
+        }
+    }
+
+    #[test]
+    fn should_evaluate_ladder_questions() {
+        let rating =
+            GeneralSystemCharacteristic::Teleprocessing.evaluate(&[true, true, true, false, false]);
+
+        assert_eq!(rating.degree(), 3);
+        assert!(rating
+            .justification()
+            .unwrap()
+            .contains("online data collection"));
+        assert_eq!(
+            GeneralSystemCharacteristic::Performance.evaluate(&[]),
+            GscRating::new(0)
+        );
+    }
+
+    #[test]
+    fn should_evaluate_checklist_questions() {
+        let complex = GeneralSystemCharacteristic::ProcessingComplexity
+            .evaluate(&[true, false, true, true, false]);
+        let change =
+            GeneralSystemCharacteristic::MaintenanceFacility.evaluate(&[false, true, true, false]);
+        let operation =
+            GeneralSystemCharacteristic::OperationFacility.evaluate(&[true, true, true, true]);
+
+        assert_eq!(complex.degree(), 3);
+        assert_eq!(change.degree(), 5);
+        assert_eq!(operation.degree(), 4);
+    }
+
+    #[test]
+    fn should_evaluate_end_user_efficiency() {
+        let mut answers = [false; 18];
+
+        answers[0] = true;
+        answers[15] = true;
+
+        assert_eq!(
+            GeneralSystemCharacteristic::EndUserEfficiency
+                .evaluate(&answers)
+                .degree(),
+            3
+        );
+
+        answers[16] = true;
+
+        assert_eq!(
+            GeneralSystemCharacteristic::EndUserEfficiency
+                .evaluate(&answers)
+                .degree(),
+            4
+        );
+    }
+}
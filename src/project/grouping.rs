@@ -0,0 +1,64 @@
+use super::summary::Summary;
+
+/// Separator of hierarchical group labels.
+pub const GROUP_SEPARATOR: char = '/';
+
+/// Size of a module or subsystem.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct GroupSize {
+    pub label: String,
+    pub components: usize,
+    pub summary: Summary,
+    pub function_points_not_adjusted: f32,
+}
+
+/// How top-level group totals add up to the project total.
+///
+/// `groups_total + ungrouped - double_counted == project_total`, where
+/// `double_counted` is the weight of components counted in more than one
+/// top-level group.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct GroupReconciliation {
+    pub groups_total: f32,
+    pub ungrouped: f32,
+    pub double_counted: f32,
+    pub project_total: f32,
+}
+
+impl GroupReconciliation {
+    /// Whether the group totals reconcile to the project total.
+    pub fn is_reconciled(&self) -> bool {
+        (self.groups_total + self.ungrouped - self.double_counted - self.project_total).abs()
+            < 0.001
+    }
+}
+
+/// Label of the top-level group of a label.
+pub fn root_group(label: &str) -> &str {
+    label.split(GROUP_SEPARATOR).next().unwrap_or(label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_root_group() {
+        assert_eq!(root_group("billing/invoices/tax"), "billing");
+        assert_eq!(root_group("portal"), "portal");
+    }
+
+    #[test]
+    fn should_reconcile() {
+        let reconciliation = GroupReconciliation {
+            groups_total: 30f32,
+            ungrouped: 5f32,
+            double_counted: 7f32,
+            project_total: 28f32,
+        };
+
+        assert!(reconciliation.is_reconciled());
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub enum CountType {
+    #[default]
+    Development,
+    Enhancement,
+    Application,
+}
+
+impl fmt::Display for CountType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CountType::Development => write!(f, "Development project"),
+            CountType::Enhancement => write!(f, "Enhancement project"),
+            CountType::Application => write!(f, "Application"),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Counter {
+    name: String,
+    certification: Option<String>,
+}
+
+impl Counter {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            certification: None,
+        }
+    }
+
+    /// Get a reference to the counter's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get a reference to the counter's certification.
+    pub fn certification(&self) -> Option<&str> {
+        self.certification.as_deref()
+    }
+
+    /// Set the counter's certification, e.g. "CFPS".
+    pub fn set_certification(&mut self, certification: impl Into<String>) -> &mut Self {
+        self.certification = Some(certification.into());
+        self
+    }
+}
+
+impl fmt::Display for Counter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.certification {
+            Some(certification) => write!(f, "{} ({})", self.name, certification),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub struct CountDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl CountDate {
+    pub fn new(year: u16, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Get the date's year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Get the date's month.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Get the date's day.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl fmt::Display for CountDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Metadata {
+    name: String,
+    count_type: CountType,
+    purpose: String,
+    scope: String,
+    boundary: String,
+    counter: Option<Counter>,
+    counting_date: Option<CountDate>,
+    method_version: String,
+    document_references: Vec<String>,
+}
+
+impl Metadata {
+    pub fn new(name: impl Into<String>, count_type: CountType) -> Self {
+        Self {
+            name: name.into(),
+            count_type,
+            ..Self::default()
+        }
+    }
+
+    /// Get a reference to the metadata's project name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get a reference to the metadata's count type.
+    pub fn count_type(&self) -> &CountType {
+        &self.count_type
+    }
+
+    /// Get a reference to the metadata's purpose statement.
+    pub fn purpose(&self) -> &str {
+        &self.purpose
+    }
+
+    /// Get a reference to the metadata's scope statement.
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    /// Get a reference to the metadata's application boundary description.
+    pub fn boundary(&self) -> &str {
+        &self.boundary
+    }
+
+    /// Get a reference to the metadata's counter.
+    pub fn counter(&self) -> Option<&Counter> {
+        self.counter.as_ref()
+    }
+
+    /// Get a reference to the metadata's counting date.
+    pub fn counting_date(&self) -> Option<&CountDate> {
+        self.counting_date.as_ref()
+    }
+
+    /// Get a reference to the metadata's counting method version.
+    pub fn method_version(&self) -> &str {
+        &self.method_version
+    }
+
+    /// Get a reference to the metadata's document references.
+    pub fn document_references(&self) -> &[String] {
+        &self.document_references
+    }
+
+    /// Set the metadata's project name.
+    pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Set the metadata's count type.
+    pub fn set_count_type(&mut self, count_type: CountType) -> &mut Self {
+        self.count_type = count_type;
+        self
+    }
+
+    /// Set the metadata's purpose statement.
+    pub fn set_purpose(&mut self, purpose: impl Into<String>) -> &mut Self {
+        self.purpose = purpose.into();
+        self
+    }
+
+    /// Set the metadata's scope statement.
+    pub fn set_scope(&mut self, scope: impl Into<String>) -> &mut Self {
+        self.scope = scope.into();
+        self
+    }
+
+    /// Set the metadata's application boundary description.
+    pub fn set_boundary(&mut self, boundary: impl Into<String>) -> &mut Self {
+        self.boundary = boundary.into();
+        self
+    }
+
+    /// Set the metadata's counter.
+    pub fn set_counter(&mut self, counter: Counter) -> &mut Self {
+        self.counter = Some(counter);
+        self
+    }
+
+    /// Set the metadata's counting date.
+    pub fn set_counting_date(&mut self, date: CountDate) -> &mut Self {
+        self.counting_date = Some(date);
+        self
+    }
+
+    /// Set the metadata's counting method version, e.g. "IFPUG CPM 4.3.1".
+    pub fn set_method_version(&mut self, method_version: impl Into<String>) -> &mut Self {
+        self.method_version = method_version.into();
+        self
+    }
+
+    /// Add a document reference.
+    pub fn add_document_reference(&mut self, reference: impl Into<String>) -> &mut Self {
+        self.document_references.push(reference.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_create() {
+        let mut counter = Counter::new("Ana Souza");
+
+        counter.set_certification("CFPS");
+
+        let mut metadata = Metadata::new("Billing", CountType::Enhancement);
+
+        metadata
+            .set_purpose("Size the 2.0 release")
+            .set_counter(counter)
+            .set_counting_date(CountDate::new(2021, 3, 9))
+            .add_document_reference("SRS v1.2");
+
+        assert_eq!(metadata.name(), "Billing");
+        assert_eq!(metadata.count_type(), &CountType::Enhancement);
+        assert_eq!(metadata.purpose(), "Size the 2.0 release");
+        assert_eq!(metadata.counter().unwrap().to_string(), "Ana Souza (CFPS)");
+        assert_eq!(metadata.counting_date().unwrap().to_string(), "2021-03-09");
+        assert_eq!(metadata.document_references(), &["SRS v1.2".to_string()]);
+    }
+}
@@ -0,0 +1,62 @@
+use super::basic_functional_component::BasicFunctionalComponent;
+
+/// MoSCoW priority of a component.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum Priority {
+    Must,
+    Should,
+    Could,
+    Wont,
+}
+
+/// Size and cost of a release, with the totals of every release up to it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct ReleaseSize {
+    pub release: String,
+    pub components: usize,
+    pub function_points_not_adjusted: f32,
+    pub adjusted_function_points: f32,
+    pub cost: f32,
+    pub cumulative_adjusted_function_points: f32,
+    pub cumulative_cost: f32,
+}
+
+/// Split of the prioritised backlog at a budget or capacity limit.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct CutLine<'a> {
+    /// Components above the cut-line, in priority order.
+    pub included: Vec<&'a BasicFunctionalComponent>,
+    /// Components below the cut-line, in priority order.
+    pub excluded: Vec<&'a BasicFunctionalComponent>,
+    pub adjusted_function_points: f32,
+    pub cost: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_order_priorities() {
+        let mut priorities = vec![
+            Priority::Wont,
+            Priority::Could,
+            Priority::Must,
+            Priority::Should,
+        ];
+
+        priorities.sort();
+
+        assert_eq!(
+            priorities,
+            vec![
+                Priority::Must,
+                Priority::Should,
+                Priority::Could,
+                Priority::Wont
+            ]
+        );
+    }
+}
@@ -0,0 +1,65 @@
+/// Delivery status of a component.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub enum DeliveryStatus {
+    #[default]
+    NotStarted,
+    /// In progress, with the percent complete from 0 to 100.
+    InProgress(u8),
+    Delivered,
+    Accepted,
+}
+
+impl DeliveryStatus {
+    /// Fraction of the component's size earned, from 0 to 1.
+    pub fn completion(&self) -> f32 {
+        match self {
+            DeliveryStatus::NotStarted => 0f32,
+            DeliveryStatus::InProgress(percent) => (*percent).min(100) as f32 / 100f32,
+            DeliveryStatus::Delivered | DeliveryStatus::Accepted => 1f32,
+        }
+    }
+}
+
+/// Earned value figures of a project at the end of a period.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct EarnedValue {
+    pub period: u32,
+    pub planned_function_points: f32,
+    pub earned_function_points: f32,
+    /// Cost of the planned function points at the productivity rate.
+    pub planned_value: f32,
+    /// Cost of the earned function points at the productivity rate.
+    pub earned_value: f32,
+    /// Cost of the hours actually spent.
+    pub actual_cost: f32,
+    /// Earned value over planned value, once something is planned.
+    pub schedule_performance_index: Option<f32>,
+    /// Earned value over actual cost, once actual hours are recorded.
+    pub cost_performance_index: Option<f32>,
+}
+
+/// Point of a burn-up chart, in adjusted function points.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct BurnUpPoint {
+    pub period: u32,
+    pub scope: f32,
+    pub planned: f32,
+    /// Earned function points recorded up to the period, if any.
+    pub earned: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_compute_completion() {
+        assert_eq!(DeliveryStatus::NotStarted.completion(), 0f32);
+        assert_eq!(DeliveryStatus::InProgress(40).completion(), 0.4f32);
+        assert_eq!(DeliveryStatus::InProgress(250).completion(), 1f32);
+        assert_eq!(DeliveryStatus::Accepted.completion(), 1f32);
+    }
+}
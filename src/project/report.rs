@@ -0,0 +1,142 @@
+use std::fmt;
+
+use super::value_adjustment::ValueAdjustment;
+use super::Project;
+
+/// Plain text count report of a project.
+pub struct Report<'a> {
+    project: &'a Project,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(project: &'a Project) -> Self {
+        Self { project }
+    }
+}
+
+impl<'a> fmt::Display for Report<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let project = self.project;
+
+        let metadata = project.metadata();
+
+        writeln!(f, "Function Point Count")?;
+        writeln!(f)?;
+
+        if !metadata.name().is_empty() {
+            writeln!(f, "Project: {}", metadata.name())?;
+        }
+
+        writeln!(f, "Count type: {}", metadata.count_type())?;
+
+        for (label, value) in &[
+            ("Purpose", metadata.purpose()),
+            ("Scope", metadata.scope()),
+            ("Boundary", metadata.boundary()),
+            ("Method", metadata.method_version()),
+        ] {
+            if !value.is_empty() {
+                writeln!(f, "{}: {}", label, value)?;
+            }
+        }
+
+        if let Some(counter) = metadata.counter() {
+            writeln!(f, "Counter: {}", counter)?;
+        }
+
+        if let Some(date) = metadata.counting_date() {
+            writeln!(f, "Counting date: {}", date)?;
+        }
+
+        for reference in metadata.document_references() {
+            writeln!(f, "Reference: {}", reference)?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Components")?;
+
+        for bfc in project.basic_functional_components() {
+            writeln!(
+                f,
+                "  {:<4} {:<40} DET {:>3}  FTR/RET {:>2}  {:<7} {:>3}",
+                bfc.functional_classification().abbreviation(),
+                bfc.name(),
+                bfc.elementary_data_referenced().total(),
+                bfc.file_record().total(),
+                bfc.complexity(),
+                project.weighting_factors().weight(bfc)
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Unadjusted function points: {:.2}",
+            project.total_function_point_not_adjusted()
+        )?;
+        writeln!(f, "Value adjustment: {}", project.value_adjustment())?;
+
+        if project.value_adjustment() != &ValueAdjustment::Disabled {
+            writeln!(
+                f,
+                "Total degree of influence: {}",
+                project.total_influence_factor()
+            )?;
+            writeln!(
+                f,
+                "Value adjustment factor: {:.2}",
+                project.final_adjustment_factor()
+            )?;
+        }
+
+        writeln!(
+            f,
+            "Adjusted function points: {:.2}",
+            project.final_adjusted_function_points()
+        )?;
+        writeln!(f, "Cost per hour: {:.2}", project.cost_per_hour())?;
+        writeln!(f, "Total cost: {:.2}", project.total_cost())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::basic_functional_component::{
+        BasicFunctionalComponent, ElementaryDataReferenced, FileRegistry, FunctionalClassification,
+    };
+    use crate::project::weighting_factors::WeightingFactors;
+
+    #[test]
+    fn should_show_value_adjustment_mode() {
+        let mut proj = Project::new();
+        let mut wf = WeightingFactors::new();
+
+        wf.set_external_query([3, 4, 6]);
+
+        let mut bfc = BasicFunctionalComponent::new(
+            "List customers",
+            FunctionalClassification::ExternalQuery,
+        );
+
+        bfc.set_edr(ElementaryDataReferenced::new(0, 5))
+            .set_file_registry(FileRegistry::new(1, 0));
+
+        proj.set_weighting_factors(wf).add_bfc(bfc);
+
+        let report = proj.report().to_string();
+
+        assert!(report.contains("Count type: Development project"));
+        assert!(report.contains("EQ   List customers"));
+        assert!(report.contains("Value adjustment: IFPUG (VAF = 0.65 + 0.01 x TDI)"));
+        assert!(report.contains("Value adjustment factor"));
+
+        proj.set_value_adjustment(ValueAdjustment::Disabled);
+
+        let report = proj.report().to_string();
+
+        assert!(report.contains("Value adjustment: Disabled, ISO/IEC 20926 (FAFP = UFP)"));
+        assert!(!report.contains("Value adjustment factor"));
+        assert!(report.contains("Adjusted function points: 3.00"));
+    }
+}
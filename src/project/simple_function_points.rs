@@ -0,0 +1,50 @@
+/// Weight of an Unspecified Generic Elementary Process (any transaction).
+pub const TRANSACTION_WEIGHT: f32 = 4.6;
+
+/// Weight of an Unspecified Generic Data Group (any data function).
+pub const DATA_GROUP_WEIGHT: f32 = 7.0;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SimpleFunctionPoints {
+    transactions: u32,
+    data_groups: u32,
+}
+
+impl SimpleFunctionPoints {
+    pub fn new(transactions: u32, data_groups: u32) -> Self {
+        Self {
+            transactions,
+            data_groups,
+        }
+    }
+
+    /// Get the number of unspecified generic transactions.
+    pub fn transactions(&self) -> u32 {
+        self.transactions
+    }
+
+    /// Get the number of unspecified generic data groups.
+    pub fn data_groups(&self) -> u32 {
+        self.data_groups
+    }
+
+    /// Compute the SiFP size.
+    pub fn total(&self) -> f32 {
+        self.transactions as f32 * TRANSACTION_WEIGHT + self.data_groups as f32 * DATA_GROUP_WEIGHT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_calculate_total() {
+        let sifp = SimpleFunctionPoints::new(10, 3);
+
+        assert_eq!(sifp.transactions(), 10);
+        assert_eq!(sifp.data_groups(), 3);
+        assert_eq!("67.0", format!("{:.1}", sifp.total()));
+    }
+}
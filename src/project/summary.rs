@@ -1,4 +1,11 @@
-#[derive(Debug, Default)]
+use std::ops::AddAssign;
+
+use super::basic_functional_component::{
+    BasicFunctionalComponent, Complexity, FunctionalClassification,
+};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct Summary {
     pub internal_logical_file: [u32; 3],
     pub external_interface_file: [u32; 3],
@@ -7,6 +14,51 @@ pub struct Summary {
     pub external_input: [u32; 3],
 }
 
+impl Summary {
+    /// Count a basic functional component in its classification and complexity.
+    pub fn increment(&mut self, bfc: &BasicFunctionalComponent) {
+        let level = match bfc.complexity() {
+            Complexity::Simple => 0,
+            Complexity::Middle => 1,
+            Complexity::Complex => 2,
+        };
+
+        match bfc.functional_classification() {
+            FunctionalClassification::ExternalInput => self.external_input[level] += 1,
+            FunctionalClassification::ExternalOutput => self.external_output[level] += 1,
+            FunctionalClassification::ExternalInterfaceFile => {
+                self.external_interface_file[level] += 1
+            }
+            FunctionalClassification::InternalLogicalFile => self.internal_logical_file[level] += 1,
+            FunctionalClassification::ExternalQuery => self.external_query[level] += 1,
+        }
+    }
+}
+
+impl AddAssign<&Summary> for Summary {
+    fn add_assign(&mut self, other: &Summary) {
+        let pairs = [
+            (
+                &mut self.internal_logical_file,
+                &other.internal_logical_file,
+            ),
+            (
+                &mut self.external_interface_file,
+                &other.external_interface_file,
+            ),
+            (&mut self.external_query, &other.external_query),
+            (&mut self.external_output, &other.external_output),
+            (&mut self.external_input, &other.external_input),
+        ];
+
+        for (totals, counts) in pairs {
+            for (total, count) in totals.iter_mut().zip(counts.iter()) {
+                *total += count;
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -33,4 +85,21 @@ mod tests {
         assert_eq!(summary.external_output, eo);
         assert_eq!(summary.external_input, ei);
     }
+
+    #[test]
+    fn should_add() {
+        let mut summary = Summary {
+            internal_logical_file: [1, 0, 0],
+            ..Summary::default()
+        };
+
+        summary += &Summary {
+            internal_logical_file: [1, 2, 0],
+            external_input: [0, 0, 3],
+            ..Summary::default()
+        };
+
+        assert_eq!(summary.internal_logical_file, [2, 2, 0]);
+        assert_eq!(summary.external_input, [0, 0, 3]);
+    }
 }
@@ -0,0 +1,122 @@
+use std::fmt;
+
+use super::Project;
+
+/// Requirement to component traceability matrix of a project.
+pub struct TraceabilityMatrix<'a> {
+    project: &'a Project,
+}
+
+impl<'a> TraceabilityMatrix<'a> {
+    pub fn new(project: &'a Project) -> Self {
+        Self { project }
+    }
+}
+
+impl<'a> fmt::Display for TraceabilityMatrix<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let project = self.project;
+
+        writeln!(f, "Traceability Matrix")?;
+        writeln!(f)?;
+
+        for requirement in project.requirements() {
+            writeln!(
+                f,
+                "{} ({} FP)",
+                requirement,
+                project.requirement_function_points(requirement)
+            )?;
+
+            for bfc in project.components_for_requirement(requirement) {
+                writeln!(
+                    f,
+                    "  {:<4} {:<40} {:>3}",
+                    bfc.functional_classification().abbreviation(),
+                    bfc.name(),
+                    project.weighting_factors().weight(bfc)
+                )?;
+            }
+        }
+
+        let untraced: Vec<_> = project
+            .basic_functional_components()
+            .iter()
+            .filter(|bfc| bfc.traceability().requirements().is_empty())
+            .collect();
+
+        if !untraced.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "Untraced components")?;
+
+            for bfc in untraced {
+                writeln!(
+                    f,
+                    "  {:<4} {}",
+                    bfc.functional_classification().abbreviation(),
+                    bfc.name()
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::basic_functional_component::{
+        BasicFunctionalComponent, ElementaryDataReferenced, FileRegistry, FunctionalClassification,
+    };
+    use crate::project::weighting_factors::WeightingFactors;
+
+    #[test]
+    fn should_trace_requirements_both_ways() {
+        let mut proj = Project::new();
+        let mut wf = WeightingFactors::new();
+
+        wf.set_referenced_logical_file([7, 10, 15])
+            .set_external_input([3, 4, 6]);
+        proj.set_weighting_factors(wf);
+
+        let mut customer = BasicFunctionalComponent::new(
+            "Customer",
+            FunctionalClassification::InternalLogicalFile,
+        );
+        let mut create = BasicFunctionalComponent::new(
+            "Create customer",
+            FunctionalClassification::ExternalInput,
+        );
+        let mut audit = BasicFunctionalComponent::new(
+            "Audit log",
+            FunctionalClassification::InternalLogicalFile,
+        );
+
+        customer
+            .set_edr(ElementaryDataReferenced::new(10, 0))
+            .set_file_registry(FileRegistry::new(1, 0))
+            .add_requirement("US-1")
+            .add_requirement("US-2");
+        create
+            .set_edr(ElementaryDataReferenced::new(4, 0))
+            .set_file_registry(FileRegistry::new(1, 0))
+            .add_requirement("US-1");
+        audit
+            .set_edr(ElementaryDataReferenced::new(4, 0))
+            .set_file_registry(FileRegistry::new(1, 0));
+
+        proj.add_bfc(customer).add_bfc(create).add_bfc(audit);
+
+        assert_eq!(proj.requirements(), vec!["US-1", "US-2"]);
+        assert_eq!(proj.components_for_requirement("US-1").len(), 2);
+        assert_eq!(proj.requirement_function_points("US-1"), 10f32);
+        assert_eq!(proj.requirement_function_points("US-2"), 7f32);
+        assert_eq!(proj.requirement_function_points("US-9"), 0f32);
+
+        let matrix = proj.traceability_matrix().to_string();
+
+        assert!(matrix.contains("US-1 (10 FP)"));
+        assert!(matrix.contains("Untraced components\n  ILF  Audit log"));
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+/// How the Value Adjustment Factor is derived from the Total Degree of Influence.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub enum ValueAdjustment {
+    /// IFPUG formula: VAF = 0.65 + 0.01 × TDI.
+    #[default]
+    Ifpug,
+    /// Organisation specific formula: VAF = base + step × TDI.
+    Custom { base: f32, step: f32 },
+    /// No adjustment, as required by ISO/IEC 20926: FAFP = UFP.
+    Disabled,
+}
+
+impl ValueAdjustment {
+    /// Compute the Value Adjustment Factor for a Total Degree of Influence.
+    pub fn factor(&self, total_influence_factor: u32) -> f32 {
+        match self {
+            ValueAdjustment::Ifpug => 0.65f32 + (0.01f32 * total_influence_factor as f32),
+            ValueAdjustment::Custom { base, step } => base + (step * total_influence_factor as f32),
+            ValueAdjustment::Disabled => 1f32,
+        }
+    }
+}
+
+impl fmt::Display for ValueAdjustment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueAdjustment::Ifpug => write!(f, "IFPUG (VAF = 0.65 + 0.01 x TDI)"),
+            ValueAdjustment::Custom { base, step } => {
+                write!(f, "Custom (VAF = {} + {} x TDI)", base, step)
+            }
+            ValueAdjustment::Disabled => write!(f, "Disabled, ISO/IEC 20926 (FAFP = UFP)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_compute_factor() {
+        assert_eq!("0.97", format!("{:.2}", ValueAdjustment::Ifpug.factor(32)));
+        assert_eq!(
+            "1.12",
+            format!(
+                "{:.2}",
+                ValueAdjustment::Custom {
+                    base: 0.8,
+                    step: 0.01
+                }
+                .factor(32)
+            )
+        );
+        assert_eq!(1f32, ValueAdjustment::Disabled.factor(32));
+    }
+}
@@ -1,4 +1,10 @@
-#[derive(Debug, Default)]
+use super::basic_functional_component::{
+    BasicFunctionalComponent, Complexity, FunctionalClassification,
+};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "python", pyo3::pyclass(from_py_object, module = "fpa_rs"))]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct WeightingFactors {
     referenced_logical_file: [u32; 3],
     external_query: [u32; 3],
@@ -31,6 +37,25 @@ impl WeightingFactors {
         &self.external_input
     }
 
+    /// Get the weight of a basic functional component according to its complexity.
+    pub fn weight(&self, bfc: &BasicFunctionalComponent) -> u32 {
+        let weights = match bfc.functional_classification() {
+            FunctionalClassification::InternalLogicalFile
+            | FunctionalClassification::ExternalInterfaceFile => &self.referenced_logical_file,
+            FunctionalClassification::ExternalInput => &self.external_input,
+            FunctionalClassification::ExternalOutput => &self.external_output,
+            FunctionalClassification::ExternalQuery => &self.external_query,
+        };
+
+        let level = match bfc.complexity() {
+            Complexity::Simple => 0,
+            Complexity::Middle => 1,
+            Complexity::Complex => 2,
+        };
+
+        weights[level]
+    }
+
     /// Set the weighting factors's referenced logical file.
     pub fn set_referenced_logical_file(&mut self, referenced_logical_file: [u32; 3]) -> &mut Self {
         self.referenced_logical_file = referenced_logical_file;
@@ -59,6 +84,7 @@ impl WeightingFactors {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::project::basic_functional_component::{ElementaryDataReferenced, FileRegistry};
 
     #[test]
     fn should_create() {
@@ -79,4 +105,29 @@ mod tests {
         assert_eq!(wf.external_output(), &eo);
         assert_eq!(wf.external_input(), &ei);
     }
+
+    #[test]
+    fn should_weight_component() {
+        let mut wf = WeightingFactors::new();
+
+        wf.set_referenced_logical_file([7, 10, 15])
+            .set_external_input([3, 4, 6]);
+
+        let mut ilf = BasicFunctionalComponent::new(
+            "Customer",
+            FunctionalClassification::InternalLogicalFile,
+        );
+        let mut ei = BasicFunctionalComponent::new(
+            "Create customer",
+            FunctionalClassification::ExternalInput,
+        );
+
+        ilf.set_edr(ElementaryDataReferenced::new(25, 0))
+            .set_file_registry(FileRegistry::new(3, 0));
+        ei.set_edr(ElementaryDataReferenced::new(4, 0))
+            .set_file_registry(FileRegistry::new(1, 0));
+
+        assert_eq!(wf.weight(&ilf), 10);
+        assert_eq!(wf.weight(&ei), 3);
+    }
 }
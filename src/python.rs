@@ -0,0 +1,557 @@
+use pyo3::exceptions::{PyIndexError, PyKeyError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::project::adjustment_factors::AdjustmentFactors;
+use crate::project::basic_functional_component::{
+    BasicFunctionalComponent, Complexity, ElementaryDataReferenced, FileRegistry,
+    FunctionalClassification,
+};
+use crate::project::general_system_characteristics::{GeneralSystemCharacteristic, MAX_DEGREE};
+use crate::project::weighting_factors::WeightingFactors;
+use crate::project::Project;
+
+/// Columns of `Project.to_records` and `Project.to_columns`.
+const COLUMNS: [&str; 7] = [
+    "name",
+    "classification",
+    "det",
+    "ftr",
+    "ret",
+    "complexity",
+    "weight",
+];
+
+fn gsc(key: &str) -> PyResult<GeneralSystemCharacteristic> {
+    GeneralSystemCharacteristic::from_key(key)
+        .ok_or_else(|| PyKeyError::new_err(format!("unknown characteristic `{}`", key)))
+}
+
+#[pymethods]
+impl FunctionalClassification {
+    #[staticmethod]
+    #[pyo3(name = "from_abbreviation")]
+    fn py_from_abbreviation(abbreviation: &str) -> PyResult<Self> {
+        Self::from_abbreviation(abbreviation).ok_or_else(|| {
+            PyValueError::new_err(format!("unknown classification `{}`", abbreviation))
+        })
+    }
+
+    #[getter(abbreviation)]
+    fn py_abbreviation(&self) -> &'static str {
+        self.abbreviation()
+    }
+
+    fn __str__(&self) -> &'static str {
+        self.abbreviation()
+    }
+}
+
+#[pymethods]
+impl Complexity {
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[pymethods]
+impl BasicFunctionalComponent {
+    /// `files` holds the FTRs of a transaction or the RETs of a data function.
+    ///
+    /// `det` and `files` count the input side and `output_det` and
+    /// `output_files` the output side, as described on [`FileRegistry`].
+    #[new]
+    #[pyo3(signature = (name, classification, det = 0, files = 0, output_det = 0, output_files = 0))]
+    fn py_new(
+        name: String,
+        classification: FunctionalClassification,
+        det: u32,
+        files: u32,
+        output_det: u32,
+        output_files: u32,
+    ) -> Self {
+        let mut bfc = Self::new(name, classification);
+
+        bfc.set_edr(ElementaryDataReferenced::new(det, output_det))
+            .set_file_registry(FileRegistry::new(files, output_files));
+
+        bfc
+    }
+
+    #[getter(name)]
+    fn py_name(&self) -> &str {
+        self.name()
+    }
+
+    #[getter]
+    fn classification(&self) -> FunctionalClassification {
+        self.functional_classification().clone()
+    }
+
+    #[getter]
+    fn det(&self) -> u32 {
+        self.elementary_data_referenced().total()
+    }
+
+    #[getter]
+    fn files(&self) -> u32 {
+        self.file_record().total()
+    }
+
+    #[getter(complexity)]
+    fn py_complexity(&self) -> Complexity {
+        self.complexity().clone()
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        let transactional = self.functional_classification().is_transactional();
+
+        dict.set_item("name", self.name())?;
+        dict.set_item(
+            "classification",
+            self.functional_classification().abbreviation(),
+        )?;
+        dict.set_item("det", self.det())?;
+        dict.set_item(
+            "ftr",
+            if transactional {
+                Some(self.files())
+            } else {
+                None
+            },
+        )?;
+        dict.set_item(
+            "ret",
+            if transactional {
+                None
+            } else {
+                Some(self.files())
+            },
+        )?;
+        dict.set_item("complexity", self.complexity().to_string())?;
+
+        Ok(dict)
+    }
+
+    fn __repr__(&self) -> String {
+        let edr = self.elementary_data_referenced();
+        let fr = self.file_record();
+        let mut repr = format!(
+            "BasicFunctionalComponent({:?}, FunctionalClassification.{}, det={}, files={}",
+            self.name(),
+            python_name(self.functional_classification()),
+            edr.input(),
+            fr.input
+        );
+
+        if *edr.output() > 0 || fr.output > 0 {
+            repr.push_str(&format!(
+                ", output_det={}, output_files={}",
+                edr.output(),
+                fr.output
+            ));
+        }
+
+        repr + ")"
+    }
+}
+
+fn python_name(classification: &FunctionalClassification) -> &'static str {
+    match classification {
+        FunctionalClassification::ExternalInput => "EXTERNAL_INPUT",
+        FunctionalClassification::ExternalOutput => "EXTERNAL_OUTPUT",
+        FunctionalClassification::ExternalQuery => "EXTERNAL_QUERY",
+        FunctionalClassification::InternalLogicalFile => "INTERNAL_LOGICAL_FILE",
+        FunctionalClassification::ExternalInterfaceFile => "EXTERNAL_INTERFACE_FILE",
+    }
+}
+
+#[pymethods]
+impl AdjustmentFactors {
+    /// Degrees given as a list of fourteen, in IFPUG order, or by keyword such
+    /// as `performance=4`.
+    #[new]
+    #[pyo3(signature = (degrees = None, **ratings))]
+    fn py_new(degrees: Option<[u8; 14]>, ratings: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let mut factors = Self::new(degrees.unwrap_or_default());
+
+        if let Some(ratings) = ratings {
+            for (key, degree) in ratings.iter() {
+                factors.py_set_degree(&key.extract::<String>()?, degree.extract()?)?;
+            }
+        }
+
+        if !factors.out_of_range().is_empty() {
+            return Err(PyValueError::new_err(format!(
+                "degrees of influence must be at most {}",
+                MAX_DEGREE
+            )));
+        }
+
+        Ok(factors)
+    }
+
+    #[pyo3(name = "degree")]
+    fn py_degree(&self, key: &str) -> PyResult<u8> {
+        Ok(self.degree(gsc(key)?))
+    }
+
+    #[pyo3(name = "set_degree")]
+    fn py_set_degree(&mut self, key: &str, degree: u8) -> PyResult<()> {
+        if degree > MAX_DEGREE {
+            return Err(PyValueError::new_err(format!(
+                "degree of influence must be at most {}",
+                MAX_DEGREE
+            )));
+        }
+
+        self.set_degree(gsc(key)?, degree);
+
+        Ok(())
+    }
+
+    /// Total degree of influence.
+    #[pyo3(name = "sum")]
+    fn py_sum(&self) -> u32 {
+        self.sum()
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+
+        for gsc in GeneralSystemCharacteristic::ALL.iter() {
+            dict.set_item(gsc.key(), self.degree(*gsc))?;
+        }
+
+        Ok(dict)
+    }
+
+    fn __repr__(&self) -> String {
+        let ratings: Vec<String> = GeneralSystemCharacteristic::ALL
+            .iter()
+            .filter(|gsc| self.degree(**gsc) > 0)
+            .map(|gsc| format!("{}={}", gsc.key(), self.degree(*gsc)))
+            .collect();
+
+        format!("AdjustmentFactors({})", ratings.join(", "))
+    }
+}
+
+#[pymethods]
+impl WeightingFactors {
+    /// Simple, average and complex weights of each kind. `ilf` also applies
+    /// to EIFs.
+    #[new]
+    #[pyo3(signature = (ei = [0; 3], eo = [0; 3], eq = [0; 3], ilf = [0; 3]))]
+    fn py_new(ei: [u32; 3], eo: [u32; 3], eq: [u32; 3], ilf: [u32; 3]) -> Self {
+        let mut factors = Self::new();
+
+        factors
+            .set_external_input(ei)
+            .set_external_output(eo)
+            .set_external_query(eq)
+            .set_referenced_logical_file(ilf);
+
+        factors
+    }
+
+    #[getter]
+    fn ei(&self) -> [u32; 3] {
+        *self.external_input()
+    }
+
+    #[getter]
+    fn eo(&self) -> [u32; 3] {
+        *self.external_output()
+    }
+
+    #[getter]
+    fn eq(&self) -> [u32; 3] {
+        *self.external_query()
+    }
+
+    #[getter]
+    fn ilf(&self) -> [u32; 3] {
+        *self.referenced_logical_file()
+    }
+
+    #[pyo3(name = "weight")]
+    fn py_weight(&self, component: &BasicFunctionalComponent) -> u32 {
+        self.weight(component)
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+
+        dict.set_item("ei", self.ei())?;
+        dict.set_item("eo", self.eo())?;
+        dict.set_item("eq", self.eq())?;
+        dict.set_item("ilf", self.ilf())?;
+
+        Ok(dict)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "WeightingFactors(ei={:?}, eo={:?}, eq={:?}, ilf={:?})",
+            self.ei(),
+            self.eo(),
+            self.eq(),
+            self.ilf()
+        )
+    }
+}
+
+#[pymethods]
+impl Project {
+    #[new]
+    #[pyo3(signature = (name = None, components = None, weighting_factors = None, adjustment_factors = None))]
+    fn py_new(
+        name: Option<String>,
+        components: Option<Vec<BasicFunctionalComponent>>,
+        weighting_factors: Option<WeightingFactors>,
+        adjustment_factors: Option<AdjustmentFactors>,
+    ) -> Self {
+        let mut project = Self::new();
+
+        if let Some(name) = name {
+            let mut metadata = project.metadata().clone();
+
+            metadata.set_name(name);
+            project.set_metadata(metadata);
+        }
+
+        if let Some(weighting_factors) = weighting_factors {
+            project.set_weighting_factors(weighting_factors);
+        }
+
+        if let Some(adjustment_factors) = adjustment_factors {
+            project.set_adjustment_factors(adjustment_factors);
+        }
+
+        for component in components.unwrap_or_default() {
+            project.add_bfc(component);
+        }
+
+        project
+    }
+
+    #[getter(name)]
+    fn py_name(&self) -> &str {
+        self.metadata().name()
+    }
+
+    #[getter]
+    fn components(&self) -> Vec<BasicFunctionalComponent> {
+        self.basic_functional_components().to_vec()
+    }
+
+    fn add(&mut self, component: BasicFunctionalComponent) {
+        self.add_bfc(component);
+    }
+
+    fn remove(&mut self, index: usize) -> PyResult<BasicFunctionalComponent> {
+        if index < self.basic_functional_components().len() {
+            Ok(self.remove_bfc(index))
+        } else {
+            Err(PyIndexError::new_err("component index out of range"))
+        }
+    }
+
+    #[getter(weighting_factors)]
+    fn py_weighting_factors(&self) -> WeightingFactors {
+        self.weighting_factors().clone()
+    }
+
+    #[setter(weighting_factors)]
+    fn py_set_weighting_factors(&mut self, weighting_factors: WeightingFactors) {
+        self.set_weighting_factors(weighting_factors);
+    }
+
+    #[getter(adjustment_factors)]
+    fn py_adjustment_factors(&self) -> AdjustmentFactors {
+        self.adjustment_factors().clone()
+    }
+
+    #[setter(adjustment_factors)]
+    fn py_set_adjustment_factors(&mut self, adjustment_factors: AdjustmentFactors) {
+        self.set_adjustment_factors(adjustment_factors);
+    }
+
+    #[getter]
+    fn unadjusted(&self) -> f32 {
+        self.total_function_point_not_adjusted()
+    }
+
+    #[getter]
+    fn total_degree_of_influence(&self) -> u32 {
+        self.total_influence_factor()
+    }
+
+    #[getter]
+    fn value_adjustment_factor(&self) -> f32 {
+        self.final_adjustment_factor()
+    }
+
+    #[getter]
+    fn adjusted(&self) -> f32 {
+        self.final_adjusted_function_points()
+    }
+
+    /// Number of components by classification and complexity.
+    #[pyo3(name = "summary")]
+    fn py_summary<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let summary = self.summary();
+        let dict = PyDict::new(py);
+
+        dict.set_item("EI", summary.external_input)?;
+        dict.set_item("EO", summary.external_output)?;
+        dict.set_item("EQ", summary.external_query)?;
+        dict.set_item("ILF", summary.internal_logical_file)?;
+        dict.set_item("EIF", summary.external_interface_file)?;
+
+        Ok(dict)
+    }
+
+    /// One dict per component, ready for `pandas.DataFrame(project.to_records())`.
+    fn to_records<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let records = PyList::empty(py);
+
+        for bfc in self.basic_functional_components() {
+            let record = bfc.to_dict(py)?;
+
+            record.set_item("weight", self.weighting_factors().weight(bfc))?;
+            records.append(record)?;
+        }
+
+        Ok(records)
+    }
+
+    /// One list per column, ready for `pandas.DataFrame(project.to_columns())`.
+    fn to_columns<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let columns = PyDict::new(py);
+
+        for column in &COLUMNS {
+            columns.set_item(column, PyList::empty(py))?;
+        }
+
+        for record in self.to_records(py)?.iter() {
+            for column in &COLUMNS {
+                columns
+                    .get_item(column)?
+                    .expect("every column is created above")
+                    .cast_into::<PyList>()?
+                    .append(record.get_item(column)?)?;
+            }
+        }
+
+        Ok(columns)
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+
+        dict.set_item("name", self.metadata().name())?;
+        dict.set_item("components", self.to_records(py)?)?;
+        dict.set_item("weighting_factors", self.weighting_factors().to_dict(py)?)?;
+        dict.set_item("adjustment_factors", self.adjustment_factors().to_dict(py)?)?;
+        dict.set_item("unadjusted", self.unadjusted())?;
+        dict.set_item("value_adjustment_factor", self.value_adjustment_factor())?;
+        dict.set_item("adjusted", self.adjusted())?;
+
+        Ok(dict)
+    }
+
+    fn __len__(&self) -> usize {
+        self.basic_functional_components().len()
+    }
+
+    fn __str__(&self) -> String {
+        self.report().to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Project({:?}, components={}, unadjusted={})",
+            self.metadata().name(),
+            self.__len__(),
+            self.unadjusted()
+        )
+    }
+}
+
+/// Python extension module, built with `maturin build --features python`.
+#[pymodule]
+fn fpa_rs(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<FunctionalClassification>()?;
+    module.add_class::<Complexity>()?;
+    module.add_class::<BasicFunctionalComponent>()?;
+    module.add_class::<AdjustmentFactors>()?;
+    module.add_class::<WeightingFactors>()?;
+    module.add_class::<Project>()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::ffi::c_str;
+
+    #[test]
+    fn should_count_from_python() {
+        Python::initialize();
+        Python::attach(|py| {
+            let globals = PyDict::new(py);
+
+            globals
+                .set_item("fpa", pyo3::wrap_pymodule!(fpa_rs)(py))
+                .unwrap();
+            py.run(
+                c_str!(
+                    r#"
+FC = fpa.FunctionalClassification
+weights = fpa.WeightingFactors(ei=(3, 4, 6), eo=(4, 5, 7), eq=(3, 4, 6), ilf=(7, 10, 15))
+project = fpa.Project(
+    "Billing",
+    components=[fpa.BasicFunctionalComponent("Customer", FC.INTERNAL_LOGICAL_FILE, det=20, files=2)],
+    weighting_factors=weights,
+    adjustment_factors=fpa.AdjustmentFactors(performance=5),
+)
+project.add(fpa.BasicFunctionalComponent("Create customer", FC.from_abbreviation("EI"), 5, 2))
+project.add(fpa.BasicFunctionalComponent("Typo", FC.EXTERNAL_QUERY))
+
+assert len(project) == 3
+assert project.remove(2).name == "Typo"
+assert project.components[1].complexity == fpa.Complexity.MIDDLE
+assert project.unadjusted == 14
+assert project.total_degree_of_influence == 5
+assert round(project.adjusted, 2) == 9.8
+assert project.summary()["ILF"] == [0, 1, 0]
+assert project.to_records()[1] == {
+    "name": "Create customer", "classification": "EI", "det": 5, "ftr": 2,
+    "ret": None, "complexity": "Middle", "weight": 4,
+}
+assert project.to_columns()["weight"] == [10, 4]
+
+invoice = fpa.BasicFunctionalComponent("Invoice", FC.EXTERNAL_OUTPUT, output_det=6, output_files=3)
+assert invoice.complexity == fpa.Complexity.MIDDLE
+assert repr(invoice).endswith("det=0, files=0, output_det=6, output_files=3)")
+assert project.to_dict()["adjustment_factors"]["performance"] == 5
+assert repr(project.adjustment_factors) == "AdjustmentFactors(performance=5)"
+
+try:
+    fpa.AdjustmentFactors(performance=6)
+    raise AssertionError("degree above five accepted")
+except ValueError:
+    pass
+"#
+                ),
+                Some(&globals),
+                None,
+            )
+            .unwrap();
+        });
+    }
+}
@@ -0,0 +1,322 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::project::Project;
+
+pub mod calibration;
+
+use calibration::Calibration;
+
+const HEADER: &str = "# fpa-rs historical repository v1";
+
+/// Completed count with its actual effort, duration and defects.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct HistoricalRecord {
+    name: String,
+    platform: String,
+    team: String,
+    function_points: f32,
+    actual_hours: f32,
+    duration_days: f32,
+    defects: u32,
+}
+
+impl HistoricalRecord {
+    pub fn new(name: impl Into<String>, function_points: f32, actual_hours: f32) -> Self {
+        Self {
+            name: name.into(),
+            function_points,
+            actual_hours,
+            ..Self::default()
+        }
+    }
+
+    /// Create a record from a completed project, sized in adjusted function points.
+    pub fn from_project(project: &Project, actual_hours: f32) -> Self {
+        Self::new(
+            project.metadata().name(),
+            project.final_adjusted_function_points(),
+            actual_hours,
+        )
+    }
+
+    /// Get a reference to the record's project name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get a reference to the record's platform.
+    pub fn platform(&self) -> &str {
+        &self.platform
+    }
+
+    /// Get a reference to the record's team.
+    pub fn team(&self) -> &str {
+        &self.team
+    }
+
+    /// Get the record's size in function points.
+    pub fn function_points(&self) -> f32 {
+        self.function_points
+    }
+
+    /// Get the record's actual effort in hours.
+    pub fn actual_hours(&self) -> f32 {
+        self.actual_hours
+    }
+
+    /// Get the record's duration in days.
+    pub fn duration_days(&self) -> f32 {
+        self.duration_days
+    }
+
+    /// Get the record's number of defects.
+    pub fn defects(&self) -> u32 {
+        self.defects
+    }
+
+    /// Hours spent per function point, unless the record has no function
+    /// points.
+    pub fn productivity(&self) -> Option<f32> {
+        if self.function_points > 0f32 {
+            Some(self.actual_hours / self.function_points)
+        } else {
+            None
+        }
+    }
+
+    /// Set the record's platform.
+    pub fn set_platform(&mut self, platform: impl Into<String>) -> &mut Self {
+        self.platform = platform.into();
+        self
+    }
+
+    /// Set the record's team.
+    pub fn set_team(&mut self, team: impl Into<String>) -> &mut Self {
+        self.team = team.into();
+        self
+    }
+
+    /// Set the record's duration in days.
+    pub fn set_duration_days(&mut self, duration_days: f32) -> &mut Self {
+        self.duration_days = duration_days;
+        self
+    }
+
+    /// Set the record's number of defects.
+    pub fn set_defects(&mut self, defects: u32) -> &mut Self {
+        self.defects = defects;
+        self
+    }
+}
+
+/// Local repository of completed counts, stored as a tab separated file.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Repository {
+    records: Vec<HistoricalRecord>,
+}
+
+impl Repository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a repository file, starting empty when it does not exist yet.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Write the repository file.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    /// Parse the content of a repository file.
+    pub fn parse(content: &str) -> io::Result<Self> {
+        let mut repository = Self::new();
+
+        for (number, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = |message: &str| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: {}", number + 1, message),
+                )
+            };
+
+            let fields: Vec<&str> = line.split('\t').collect();
+
+            if fields.len() != 7 {
+                return Err(invalid("expected 7 tab separated fields"));
+            }
+
+            let number_field = |i: usize| {
+                fields[i]
+                    .parse::<f32>()
+                    .map_err(|_| invalid(&format!("invalid number {:?}", fields[i])))
+            };
+
+            let mut record =
+                HistoricalRecord::new(unescape(fields[0]), number_field(3)?, number_field(4)?);
+
+            record
+                .set_platform(unescape(fields[1]))
+                .set_team(unescape(fields[2]))
+                .set_duration_days(number_field(5)?)
+                .set_defects(
+                    fields[6]
+                        .parse()
+                        .map_err(|_| invalid(&format!("invalid defects {:?}", fields[6])))?,
+                );
+
+            repository.add(record);
+        }
+
+        Ok(repository)
+    }
+
+    /// Add a completed count.
+    pub fn add(&mut self, record: HistoricalRecord) -> &mut Self {
+        self.records.push(record);
+        self
+    }
+
+    /// Get a reference to the repository's records.
+    pub fn records(&self) -> &[HistoricalRecord] {
+        &self.records
+    }
+
+    /// Records matching a platform and a team, when given.
+    pub fn filter(&self, platform: Option<&str>, team: Option<&str>) -> Vec<&HistoricalRecord> {
+        self.records
+            .iter()
+            .filter(|record| platform.is_none_or(|p| record.platform == p))
+            .filter(|record| team.is_none_or(|t| record.team == t))
+            .collect()
+    }
+
+    /// Calibrate productivity on the records matching a platform and a team, when given.
+    pub fn calibrate(&self, platform: Option<&str>, team: Option<&str>) -> Option<Calibration> {
+        Calibration::fit(&self.filter(platform, team))
+    }
+}
+
+impl fmt::Display for Repository {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+
+        for record in &self.records {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                escape(&record.name),
+                escape(&record.platform),
+                escape(&record.team),
+                record.function_points,
+                record.actual_hours,
+                record.duration_days,
+                record.defects
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn escape(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n");
+
+    // A leading `#` would read back as a comment line.
+    if escaped.starts_with('#') {
+        format!("\\{}", escaped)
+    } else {
+        escaped
+    }
+}
+
+fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('t') => result.push('\t'),
+                Some('n') => result.push('\n'),
+                Some(other) => result.push(other),
+                None => result.push('\\'),
+            }
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_save_and_open() {
+        let mut repository = Repository::new();
+        let mut record = HistoricalRecord::new("#42 Billing\t2.0", 120f32, 960f32);
+
+        record
+            .set_platform("web")
+            .set_team("alpha")
+            .set_duration_days(90f32)
+            .set_defects(14);
+        repository
+            .add(record)
+            .add(HistoricalRecord::new("Batch", 80f32, 400f32));
+
+        let path =
+            std::env::temp_dir().join(format!("fpa-rs-repository-{}.tsv", std::process::id()));
+
+        repository.save(&path).unwrap();
+
+        let restored = Repository::open(&path).unwrap();
+
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(restored, repository);
+        assert_eq!(restored.records()[0].name(), "#42 Billing\t2.0");
+        assert_eq!(restored.records()[0].productivity(), Some(8f32));
+        assert_eq!(
+            HistoricalRecord::new("Empty", 0f32, 10f32).productivity(),
+            None
+        );
+        assert_eq!(restored.filter(Some("web"), None).len(), 1);
+        assert_eq!(restored.filter(None, Some("beta")).len(), 0);
+        assert_eq!(restored.calibrate(Some("web"), None), None);
+    }
+
+    #[test]
+    fn should_report_invalid_lines() {
+        let error =
+            Repository::parse("# header\nBilling\tweb\talpha\tmany\t960\t90\t14\n").unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "line 2: invalid number \"many\"");
+        assert!(Repository::open("/nonexistent/fpa-rs.tsv")
+            .unwrap()
+            .records()
+            .is_empty());
+    }
+}
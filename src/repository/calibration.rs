@@ -0,0 +1,155 @@
+use super::HistoricalRecord;
+use crate::project::Project;
+
+/// Two-sided 95% Student's t quantiles for 1 to 30 degrees of freedom.
+const T_95: [f32; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+    2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+    2.052, 2.048, 2.045, 2.042,
+];
+
+/// Least squares regression of actual hours against function points.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct Calibration {
+    samples: usize,
+    slope: f32,
+    intercept: f32,
+    productivity_rate: f32,
+    residual_standard_error: f32,
+    mean_function_points: f32,
+    sum_of_squares: f32,
+}
+
+/// Predicted effort with its 95% prediction interval.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Debug, Clone)]
+pub struct Prediction {
+    pub hours: f32,
+    pub lower: f32,
+    pub upper: f32,
+}
+
+impl Calibration {
+    /// Fit the regression, which needs at least three records of different sizes.
+    pub fn fit(records: &[&HistoricalRecord]) -> Option<Self> {
+        let n = records.len();
+
+        if n < 3 {
+            return None;
+        }
+
+        let mean_x = records.iter().map(|r| r.function_points()).sum::<f32>() / n as f32;
+        let mean_y = records.iter().map(|r| r.actual_hours()).sum::<f32>() / n as f32;
+        let sxx: f32 = records
+            .iter()
+            .map(|r| (r.function_points() - mean_x).powi(2))
+            .sum();
+
+        if sxx <= 0f32 {
+            return None;
+        }
+
+        let sxy: f32 = records
+            .iter()
+            .map(|r| (r.function_points() - mean_x) * (r.actual_hours() - mean_y))
+            .sum();
+        let slope = sxy / sxx;
+        let intercept = mean_y - slope * mean_x;
+        let sse: f32 = records
+            .iter()
+            .map(|r| (r.actual_hours() - (intercept + slope * r.function_points())).powi(2))
+            .sum();
+
+        Some(Calibration {
+            samples: n,
+            slope,
+            intercept,
+            productivity_rate: mean_y / mean_x,
+            residual_standard_error: (sse / (n - 2) as f32).sqrt(),
+            mean_function_points: mean_x,
+            sum_of_squares: sxx,
+        })
+    }
+
+    /// Get the number of records the calibration was fitted on.
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// Get the marginal hours per function point of the regression.
+    pub fn slope(&self) -> f32 {
+        self.slope
+    }
+
+    /// Get the fixed hours of the regression.
+    pub fn intercept(&self) -> f32 {
+        self.intercept
+    }
+
+    /// Recommended productivity rate, in hours per function point, over all records.
+    pub fn productivity_rate(&self) -> f32 {
+        self.productivity_rate
+    }
+
+    /// Predict the effort of a size in function points.
+    pub fn predict(&self, function_points: f32) -> Prediction {
+        let degrees = self.samples - 2;
+        let t = T_95.get(degrees - 1).copied().unwrap_or(1.960);
+        let hours = self.intercept + self.slope * function_points;
+        let margin = t
+            * self.residual_standard_error
+            * (1f32
+                + 1f32 / self.samples as f32
+                + (function_points - self.mean_function_points).powi(2) / self.sum_of_squares)
+                .sqrt();
+
+        Prediction {
+            hours,
+            lower: hours - margin,
+            upper: hours + margin,
+        }
+    }
+
+    /// Predict the effort of a project from its adjusted function points.
+    pub fn predict_project(&self, project: &Project) -> Prediction {
+        self.predict(project.final_adjusted_function_points())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_fit_and_predict() {
+        let records = [
+            HistoricalRecord::new("A", 100f32, 810f32),
+            HistoricalRecord::new("B", 200f32, 1590f32),
+            HistoricalRecord::new("C", 300f32, 2420f32),
+            HistoricalRecord::new("D", 400f32, 3180f32),
+        ];
+        let references: Vec<&HistoricalRecord> = records.iter().collect();
+
+        let calibration = Calibration::fit(&references).unwrap();
+
+        assert_eq!(calibration.samples(), 4);
+        assert_eq!("7.94", format!("{:.2}", calibration.slope()));
+        assert_eq!("8.00", format!("{:.2}", calibration.productivity_rate()));
+
+        let prediction = calibration.predict(250f32);
+
+        assert_eq!("2000", format!("{:.0}", prediction.hours));
+        assert!(prediction.lower < prediction.hours && prediction.hours < prediction.upper);
+        assert!(prediction.upper - prediction.lower < 200f32);
+    }
+
+    #[test]
+    fn should_need_enough_records() {
+        let a = HistoricalRecord::new("A", 100f32, 800f32);
+        let b = HistoricalRecord::new("B", 100f32, 900f32);
+
+        assert_eq!(Calibration::fit(&[&a, &b]), None);
+        assert_eq!(Calibration::fit(&[&a, &b, &a]), None);
+    }
+}
@@ -0,0 +1,504 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::project::basic_functional_component::{
+    BasicFunctionalComponent, ElementaryDataReferenced, FileRegistry, FunctionalClassification,
+};
+use crate::project::general_system_characteristics::{GeneralSystemCharacteristic, MAX_DEGREE};
+use crate::project::Project;
+
+/// Largest request body accepted, in bytes.
+const MAX_BODY: usize = 1 << 20;
+
+/// Largest request line and headers accepted, in bytes.
+const MAX_HEAD: u64 = 16 << 10;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
+struct State {
+    next_id: u64,
+    projects: BTreeMap<u64, Project>,
+}
+
+#[derive(serde::Deserialize)]
+struct ComponentBody {
+    name: String,
+    kind: String,
+    #[serde(default)]
+    det: u32,
+    #[serde(default)]
+    det_out: u32,
+    ftr: Option<u32>,
+    ftr_out: Option<u32>,
+    ret: Option<u32>,
+}
+
+struct Response {
+    status: u16,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: Value) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: body.to_string(),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self::json(status, json!({ "error": message.into() }))
+    }
+
+    fn no_content() -> Self {
+        Self {
+            status: 204,
+            content_type: "application/json",
+            body: String::new(),
+        }
+    }
+}
+
+/// Counting engine exposed as a local HTTP/JSON service.
+///
+/// | Method | Path | Body |
+/// | --- | --- | --- |
+/// | `GET`, `POST` | `/projects` | `{"name": "Billing"}` |
+/// | `GET`, `DELETE` | `/projects/{id}` | |
+/// | `POST` | `/projects/{id}/components` | `{"name": "Create customer", "kind": "EI", "det": 5, "ftr": 2}` |
+/// | `PUT`, `DELETE` | `/projects/{id}/components/{index}` | as above |
+/// | `PUT` | `/projects/{id}/gsc` | `{"performance": 4}` |
+/// | `PUT` | `/projects/{id}/weights` | `{"EI": [3, 4, 6], "ILF": [7, 10, 15]}` |
+/// | `GET` | `/projects/{id}/summary`, `/totals`, `/report` | |
+///
+/// As in the text format, `det` and `ftr` count the input side of a
+/// transaction and `det_out` and `ftr_out` its output side.
+///
+/// Projects live in memory, or in a local JSON file saved after every change.
+/// Bodies larger than 1 MiB are answered with `413`.
+#[derive(Debug)]
+pub struct CountingService {
+    state: State,
+    path: Option<PathBuf>,
+    read_timeout: Duration,
+}
+
+impl Default for CountingService {
+    fn default() -> Self {
+        Self {
+            state: State::default(),
+            path: None,
+            read_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+impl CountingService {
+    /// Create a service keeping its projects in memory.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a service keeping its projects in the file at `path`, which is
+    /// created on the first change if missing.
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let state = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => State::default(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self {
+            state,
+            path: Some(path),
+            ..Self::default()
+        })
+    }
+
+    /// Set how long a client may take to send its whole request, five
+    /// seconds by default.
+    pub fn set_read_timeout(&mut self, read_timeout: Duration) -> &mut Self {
+        self.read_timeout = read_timeout;
+        self
+    }
+
+    /// Answer the connections of `listener` one at a time, forever.
+    pub fn serve(&mut self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            if let Err(e) = self.answer(stream?) {
+                eprintln!("fpa-server: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn answer(&mut self, stream: TcpStream) -> io::Result<()> {
+        let mut reader = BufReader::new(
+            Deadline {
+                stream: stream.try_clone()?,
+                deadline: Instant::now() + self.read_timeout,
+            }
+            .take(MAX_HEAD),
+        );
+        let mut request_line = String::new();
+
+        reader.read_line(&mut request_line)?;
+
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default().to_string();
+        let target = parts.next().unwrap_or_default().to_string();
+        let mut length = 0;
+
+        loop {
+            let mut header = String::new();
+
+            if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+                break;
+            }
+
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("Content-Length") {
+                    length = value.trim().parse().unwrap_or_default();
+                }
+            }
+        }
+
+        let response = if length > MAX_BODY {
+            Response::error(413, format!("body must be at most {} bytes", MAX_BODY))
+        } else {
+            let mut body = vec![0; length];
+
+            reader.get_mut().set_limit(length as u64);
+            reader.read_exact(&mut body)?;
+
+            match String::from_utf8(body) {
+                Ok(body) => self.handle(&method, &target, &body),
+                Err(_) => Response::error(400, "body must be UTF-8"),
+            }
+        };
+
+        let mut stream = stream;
+
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            response.status,
+            reason(response.status),
+            response.content_type,
+            response.body.len(),
+            response.body
+        )?;
+        stream.flush()
+    }
+
+    fn handle(&mut self, method: &str, target: &str, body: &str) -> Response {
+        let path = target.split('?').next().unwrap_or_default();
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        // Kept to undo a change that could not be saved.
+        let previous = if method == "GET" {
+            None
+        } else {
+            Some(self.state.clone())
+        };
+
+        let response = match (method, segments.as_slice()) {
+            ("GET", ["projects"]) => Ok(self.list()),
+            ("POST", ["projects"]) => self.create(body),
+            (_, ["projects"]) => Err(Response::error(405, "method not allowed")),
+            (_, ["projects", id, rest @ ..]) => {
+                let id: u64 = match id.parse() {
+                    Ok(id) => id,
+                    Err(_) => return Response::error(404, "project not found"),
+                };
+
+                self.project_route(method, id, rest, body)
+            }
+            _ => Err(Response::error(404, "not found")),
+        };
+
+        let response = response.unwrap_or_else(|error| error);
+
+        if let Some(previous) = previous.filter(|_| response.status < 300) {
+            if let Err(e) = self.save() {
+                self.state = previous;
+
+                return Response::error(500, format!("could not save state: {}", e));
+            }
+        }
+
+        response
+    }
+
+    fn list(&self) -> Response {
+        let projects: Vec<Value> = self
+            .state
+            .projects
+            .iter()
+            .map(|(id, project)| {
+                json!({
+                    "id": id,
+                    "name": project.metadata().name(),
+                    "unadjusted": project.total_function_point_not_adjusted(),
+                })
+            })
+            .collect();
+
+        Response::json(200, json!(projects))
+    }
+
+    fn create(&mut self, body: &str) -> Result<Response, Response> {
+        let request: Value = parse(body)?;
+        let mut project = Project::new();
+        let mut metadata = project.metadata().clone();
+
+        if let Some(name) = request["name"].as_str() {
+            metadata.set_name(name);
+        }
+
+        project.set_metadata(metadata);
+
+        self.state.next_id += 1;
+
+        let id = self.state.next_id;
+
+        self.state.projects.insert(id, project);
+
+        Ok(Response::json(201, json!({ "id": id })))
+    }
+
+    fn project_route(
+        &mut self,
+        method: &str,
+        id: u64,
+        rest: &[&str],
+        body: &str,
+    ) -> Result<Response, Response> {
+        if method == "DELETE" && rest.is_empty() {
+            return match self.state.projects.remove(&id) {
+                Some(_) => Ok(Response::no_content()),
+                None => Err(Response::error(404, "project not found")),
+            };
+        }
+
+        let project = self
+            .state
+            .projects
+            .get_mut(&id)
+            .ok_or_else(|| Response::error(404, "project not found"))?;
+
+        match (method, rest) {
+            ("GET", []) => Ok(Response::json(200, json!(project))),
+            ("GET", ["summary"]) => Ok(Response::json(200, json!(project.summary()))),
+            ("GET", ["totals"]) => Ok(Response::json(200, totals(project))),
+            ("GET", ["report"]) => Ok(Response {
+                status: 200,
+                content_type: "text/plain; charset=utf-8",
+                body: project.report().to_string(),
+            }),
+            ("POST", ["components"]) => {
+                project.add_bfc(component(body)?);
+
+                let index = project.basic_functional_components().len() - 1;
+
+                Ok(Response::json(201, json!({ "index": index })))
+            }
+            (_, ["components", index]) => {
+                let index = index
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|i| *i < project.basic_functional_components().len())
+                    .ok_or_else(|| Response::error(404, "component not found"))?;
+
+                match method {
+                    "GET" => Ok(Response::json(
+                        200,
+                        json!(project.basic_functional_components()[index]),
+                    )),
+                    "PUT" => {
+                        project.replace_bfc(index, component(body)?);
+                        Ok(Response::json(200, json!({ "index": index })))
+                    }
+                    "DELETE" => {
+                        project.remove_bfc(index);
+                        Ok(Response::no_content())
+                    }
+                    _ => Err(Response::error(405, "method not allowed")),
+                }
+            }
+            ("PUT", ["gsc"]) => {
+                let request = parse(body)?;
+                let ratings = request
+                    .as_object()
+                    .ok_or_else(|| Response::error(400, "expected an object of degrees"))?;
+                let mut factors = project.adjustment_factors().clone();
+
+                for (key, value) in ratings {
+                    let gsc = GeneralSystemCharacteristic::from_key(key).ok_or_else(|| {
+                        Response::error(400, format!("unknown characteristic `{}`", key))
+                    })?;
+                    let degree = value
+                        .as_u64()
+                        .filter(|degree| *degree <= MAX_DEGREE as u64)
+                        .ok_or_else(|| {
+                            Response::error(
+                                400,
+                                format!("`{}` must be a degree from 0 to {}", key, MAX_DEGREE),
+                            )
+                        })?;
+
+                    factors.set_degree(gsc, degree as u8);
+                }
+
+                project.set_adjustment_factors(factors);
+
+                Ok(Response::json(200, totals(project)))
+            }
+            ("PUT", ["weights"]) => {
+                let request = parse(body)?;
+                let weights = request
+                    .as_object()
+                    .ok_or_else(|| Response::error(400, "expected an object of weights"))?;
+                let mut factors = project.weighting_factors().clone();
+
+                for (key, value) in weights {
+                    let values: [u32; 3] = serde_json::from_value(value.clone()).map_err(|_| {
+                        Response::error(
+                            400,
+                            format!("`{}` expects simple, average and complex weights", key),
+                        )
+                    })?;
+
+                    match key.as_str() {
+                        "EI" => factors.set_external_input(values),
+                        "EO" => factors.set_external_output(values),
+                        "EQ" => factors.set_external_query(values),
+                        "ILF" => factors.set_referenced_logical_file(values),
+                        other => {
+                            return Err(Response::error(
+                                400,
+                                format!("unknown weights `{}`, expected EI, EO, EQ or ILF", other),
+                            ))
+                        }
+                    };
+                }
+
+                project.set_weighting_factors(factors);
+
+                Ok(Response::json(200, totals(project)))
+            }
+            ("GET", _) => Err(Response::error(404, "not found")),
+            _ => Err(Response::error(405, "method not allowed")),
+        }
+    }
+
+    fn save(&self) -> io::Result<()> {
+        match &self.path {
+            Some(path) => {
+                // Replace the file in one step so a crash never leaves it
+                // half written.
+                let mut temporary = path.clone().into_os_string();
+
+                temporary.push(".tmp");
+                fs::write(&temporary, serde_json::to_string(&self.state)?)?;
+                fs::rename(&temporary, path)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Stream failing reads once the time given to send a request is over, so
+/// a client trickling bytes can not hold the server.
+struct Deadline {
+    stream: TcpStream,
+    deadline: Instant,
+}
+
+impl Read for Deadline {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self
+            .deadline
+            .checked_duration_since(Instant::now())
+            .filter(|remaining| !remaining.is_zero())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::TimedOut, "request timed out"))?;
+
+        self.stream.set_read_timeout(Some(remaining))?;
+        self.stream.read(buf)
+    }
+}
+
+fn parse(body: &str) -> Result<Value, Response> {
+    if body.trim().is_empty() {
+        return Ok(json!({}));
+    }
+
+    serde_json::from_str(body).map_err(|e| Response::error(400, format!("invalid JSON: {}", e)))
+}
+
+fn component(body: &str) -> Result<BasicFunctionalComponent, Response> {
+    let request: ComponentBody =
+        serde_json::from_str(body).map_err(|e| Response::error(400, e.to_string()))?;
+    let classification =
+        FunctionalClassification::from_abbreviation(&request.kind).ok_or_else(|| {
+            Response::error(
+                400,
+                format!(
+                    "unknown kind `{}`, expected EI, EO, EQ, ILF or EIF",
+                    request.kind
+                ),
+            )
+        })?;
+
+    let files = match (classification.is_transactional(), request.ftr, request.ret) {
+        (true, files, None) | (false, None, files) => files.unwrap_or_default(),
+        (true, _, Some(_)) => return Err(Response::error(400, "`ret` applies to data functions")),
+        (false, Some(_), _) => return Err(Response::error(400, "`ftr` applies to transactions")),
+    };
+
+    if !classification.is_transactional() && request.ftr_out.is_some() {
+        return Err(Response::error(400, "`ftr_out` applies to transactions"));
+    }
+
+    let mut bfc = BasicFunctionalComponent::new(request.name, classification);
+
+    bfc.set_edr(ElementaryDataReferenced::new(request.det, request.det_out))
+        .set_file_registry(FileRegistry::new(
+            files,
+            request.ftr_out.unwrap_or_default(),
+        ));
+
+    Ok(bfc)
+}
+
+fn totals(project: &Project) -> Value {
+    json!({
+        "unadjusted": project.total_function_point_not_adjusted(),
+        "total_influence": project.total_influence_factor(),
+        "adjustment_factor": project.final_adjustment_factor(),
+        "adjusted": project.final_adjusted_function_points(),
+        "cost": project.total_cost(),
+    })
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        _ => "Internal Server Error",
+    }
+}
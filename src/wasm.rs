@@ -0,0 +1,275 @@
+use wasm_bindgen::prelude::*;
+
+use crate::project::basic_functional_component::{
+    BasicFunctionalComponent, ElementaryDataReferenced, FileRegistry, FunctionalClassification,
+};
+use crate::project::general_system_characteristics::{GeneralSystemCharacteristic, MAX_DEGREE};
+use crate::project::Project;
+
+/// Project exposed to JavaScript as `Project`.
+///
+/// ```js
+/// const project = new Project("Billing");
+/// project.setWeights("EI", 3, 4, 6);
+/// project.addComponent("EI", "Create customer", 5, 2);
+/// project.setGsc("performance", 4);
+/// project.unadjusted();
+/// ```
+#[wasm_bindgen(js_name = Project)]
+#[derive(Debug, Default, Clone)]
+pub struct WasmProject {
+    project: Project,
+}
+
+#[wasm_bindgen(js_class = Project)]
+impl WasmProject {
+    #[wasm_bindgen(constructor)]
+    pub fn new(name: Option<String>) -> Self {
+        let mut project = Project::new();
+
+        if let Some(name) = name {
+            let mut metadata = project.metadata().clone();
+
+            metadata.set_name(name);
+            project.set_metadata(metadata);
+        }
+
+        Self { project }
+    }
+
+    /// Read a project serialized by `toJson`, computing its totals again from
+    /// the components.
+    #[wasm_bindgen(js_name = fromJson)]
+    pub fn from_json(json: &str) -> Result<WasmProject, JsError> {
+        Ok(Self {
+            project: serde_json::from_str(json)?,
+        })
+    }
+
+    #[wasm_bindgen(js_name = toJson)]
+    pub fn to_json(&self) -> Result<String, JsError> {
+        Ok(serde_json::to_string(&self.project)?)
+    }
+
+    /// Add a component of kind `EI`, `EO`, `EQ`, `ILF` or `EIF`, with its FTRs
+    /// or RETs as `files`, and return its index.
+    ///
+    /// `det` and `files` count the input side of the component, and
+    /// `output_det` and `output_files` its output side, as described on
+    /// [`FileRegistry`].
+    #[wasm_bindgen(js_name = addComponent)]
+    pub fn add_component(
+        &mut self,
+        kind: &str,
+        name: &str,
+        det: u32,
+        files: u32,
+        output_det: Option<u32>,
+        output_files: Option<u32>,
+    ) -> Result<usize, JsError> {
+        let classification = FunctionalClassification::from_abbreviation(kind)
+            .ok_or_else(|| JsError::new(&format!("unknown component kind `{}`", kind)))?;
+        let mut bfc = BasicFunctionalComponent::new(name, classification);
+
+        bfc.set_edr(ElementaryDataReferenced::new(
+            det,
+            output_det.unwrap_or_default(),
+        ))
+        .set_file_registry(FileRegistry::new(files, output_files.unwrap_or_default()));
+        self.project.add_bfc(bfc);
+
+        Ok(self.len() - 1)
+    }
+
+    #[wasm_bindgen(js_name = removeComponent)]
+    pub fn remove_component(&mut self, index: usize) -> Result<(), JsError> {
+        self.check(index)?;
+        self.project.remove_bfc(index);
+
+        Ok(())
+    }
+
+    /// Number of components.
+    #[wasm_bindgen(getter)]
+    pub fn len(&self) -> usize {
+        self.project.basic_functional_components().len()
+    }
+
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[wasm_bindgen(js_name = componentComplexity)]
+    pub fn component_complexity(&self, index: usize) -> Result<String, JsError> {
+        self.check(index)?;
+
+        Ok(self.project.basic_functional_components()[index]
+            .complexity()
+            .to_string())
+    }
+
+    #[wasm_bindgen(js_name = componentWeight)]
+    pub fn component_weight(&self, index: usize) -> Result<u32, JsError> {
+        self.check(index)?;
+
+        let bfc = &self.project.basic_functional_components()[index];
+
+        Ok(self.project.weighting_factors().weight(bfc))
+    }
+
+    /// Set the simple, average and complex weights of `EI`, `EO`, `EQ` or
+    /// `ILF`, which also applies to EIFs.
+    #[wasm_bindgen(js_name = setWeights)]
+    pub fn set_weights(
+        &mut self,
+        kind: &str,
+        simple: u32,
+        average: u32,
+        complex: u32,
+    ) -> Result<(), JsError> {
+        let mut factors = self.project.weighting_factors().clone();
+        let weights = [simple, average, complex];
+
+        match kind {
+            "EI" => factors.set_external_input(weights),
+            "EO" => factors.set_external_output(weights),
+            "EQ" => factors.set_external_query(weights),
+            "ILF" => factors.set_referenced_logical_file(weights),
+            other => return Err(JsError::new(&format!("unknown weights `{}`", other))),
+        };
+
+        self.project.set_weighting_factors(factors);
+
+        Ok(())
+    }
+
+    /// Rate a general system characteristic by its snake case key, such as
+    /// `performance` or `online_data_input`.
+    #[wasm_bindgen(js_name = setGsc)]
+    pub fn set_gsc(&mut self, key: &str, degree: u8) -> Result<(), JsError> {
+        let gsc = GeneralSystemCharacteristic::from_key(key)
+            .ok_or_else(|| JsError::new(&format!("unknown characteristic `{}`", key)))?;
+
+        if degree > MAX_DEGREE {
+            return Err(JsError::new(&format!(
+                "degree of influence must be at most {}",
+                MAX_DEGREE
+            )));
+        }
+
+        let mut factors = self.project.adjustment_factors().clone();
+
+        factors.set_degree(gsc, degree);
+        self.project.set_adjustment_factors(factors);
+
+        Ok(())
+    }
+
+    pub fn unadjusted(&self) -> f32 {
+        self.project.total_function_point_not_adjusted()
+    }
+
+    #[wasm_bindgen(js_name = totalInfluence)]
+    pub fn total_influence(&self) -> u32 {
+        self.project.total_influence_factor()
+    }
+
+    #[wasm_bindgen(js_name = adjustmentFactor)]
+    pub fn adjustment_factor(&self) -> f32 {
+        self.project.final_adjustment_factor()
+    }
+
+    pub fn adjusted(&self) -> f32 {
+        self.project.final_adjusted_function_points()
+    }
+
+    pub fn report(&self) -> String {
+        self.project.report().to_string()
+    }
+
+    fn check(&self, index: usize) -> Result<(), JsError> {
+        if index < self.len() {
+            Ok(())
+        } else {
+            Err(JsError::new(&format!("no component at index {}", index)))
+        }
+    }
+}
+
+impl WasmProject {
+    /// Get a reference to the wrapped project.
+    pub fn project(&self) -> &Project {
+        &self.project
+    }
+}
+
+impl From<Project> for WasmProject {
+    fn from(project: Project) -> Self {
+        Self { project }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_count_through_bindings() {
+        let mut project = WasmProject::new(Some("Billing".to_string()));
+
+        project.set_weights("EI", 3, 4, 6).unwrap();
+        project.set_weights("ILF", 7, 10, 15).unwrap();
+        project
+            .add_component("ILF", "Customer", 20, 2, None, None)
+            .unwrap();
+
+        assert_eq!(
+            project
+                .add_component("EI", "Create customer", 5, 2, None, None)
+                .unwrap(),
+            1
+        );
+        assert_eq!(project.component_complexity(1).unwrap(), "Middle");
+        assert_eq!(project.component_weight(0).unwrap(), 10);
+        assert_eq!(project.unadjusted(), 14f32);
+
+        project.set_gsc("performance", 5).unwrap();
+        project.remove_component(0).unwrap();
+
+        assert_eq!(project.total_influence(), 5);
+        assert_eq!(project.unadjusted(), 4f32);
+        assert_eq!(format!("{:.2}", project.adjusted()), "2.80");
+
+        let copy = WasmProject::from_json(&project.to_json().unwrap()).unwrap();
+
+        assert_eq!(copy.project().metadata().name(), "Billing");
+        assert_eq!(
+            copy.project().basic_functional_components(),
+            project.project().basic_functional_components()
+        );
+    }
+
+    #[test]
+    fn should_count_output_side_of_external_outputs() {
+        let mut project = WasmProject::new(None);
+
+        project.set_weights("EO", 4, 5, 7).unwrap();
+        project
+            .add_component("EO", "Invoice", 0, 0, Some(6), Some(3))
+            .unwrap();
+
+        assert_eq!(project.component_complexity(0).unwrap(), "Middle");
+
+        let mut json: serde_json::Value =
+            serde_json::from_str(&project.to_json().unwrap()).unwrap();
+
+        json["total_function_point_not_adjusted"] = serde_json::json!(999);
+        json["basic_functional_components"][0]["complexity"] = serde_json::json!("Complex");
+
+        let copy = WasmProject::from_json(&json.to_string()).unwrap();
+
+        assert_eq!(copy.component_complexity(0).unwrap(), "Middle");
+        assert_eq!(copy.unadjusted(), 5f32);
+    }
+}
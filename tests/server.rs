@@ -0,0 +1,327 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use std::{env, fs, process, thread};
+
+use fpa_rs::CountingService;
+use serde_json::{json, Value};
+
+fn spawn(mut service: CountingService) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    thread::spawn(move || service.serve(listener));
+
+    address
+}
+
+fn raw_request(address: SocketAddr, head: &str) -> u16 {
+    let mut stream = TcpStream::connect(address).unwrap();
+    let mut response = String::new();
+
+    stream.write_all(head.as_bytes()).unwrap();
+    stream.read_to_string(&mut response).unwrap();
+
+    response[9..12].parse().unwrap()
+}
+
+fn request(address: SocketAddr, method: &str, path: &str, body: Option<Value>) -> (u16, String) {
+    let body = body.map(|b| b.to_string()).unwrap_or_default();
+    let mut stream = TcpStream::connect(address).unwrap();
+
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+        method,
+        path,
+        body.len(),
+        body
+    )
+    .unwrap();
+
+    let mut response = String::new();
+
+    stream.read_to_string(&mut response).unwrap();
+
+    let status = response[9..12].parse().unwrap();
+    let body = response
+        .split_once("\r\n\r\n")
+        .map(|(_, body)| body.to_string())
+        .unwrap_or_default();
+
+    (status, body)
+}
+
+fn json_request(address: SocketAddr, method: &str, path: &str, body: Option<Value>) -> Value {
+    let (status, body) = request(address, method, path, body);
+
+    assert!(
+        status < 300,
+        "{} {} answered {}: {}",
+        method,
+        path,
+        status,
+        body
+    );
+
+    serde_json::from_str(&body).unwrap()
+}
+
+fn state_file(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("fpa-server-{}-{}.json", name, process::id()));
+
+    let _ = fs::remove_file(&path);
+
+    path
+}
+
+#[test]
+fn should_count_a_project() {
+    let address = spawn(CountingService::new());
+
+    let created = json_request(
+        address,
+        "POST",
+        "/projects",
+        Some(json!({ "name": "Billing" })),
+    );
+    let project = format!("/projects/{}", created["id"]);
+
+    json_request(
+        address,
+        "PUT",
+        &format!("{}/weights", project),
+        Some(json!({ "EI": [3, 4, 6], "EO": [4, 5, 7], "EQ": [3, 4, 6], "ILF": [7, 10, 15] })),
+    );
+
+    for component in &[
+        json!({ "name": "Customer", "kind": "ILF", "det": 20, "ret": 2 }),
+        json!({ "name": "Create customer", "kind": "EI", "det": 5, "ftr": 2 }),
+        json!({ "name": "Typo", "kind": "EQ" }),
+    ] {
+        json_request(
+            address,
+            "POST",
+            &format!("{}/components", project),
+            Some(component.clone()),
+        );
+    }
+
+    json_request(
+        address,
+        "PUT",
+        &format!("{}/components/2", project),
+        Some(json!({ "name": "List customers", "kind": "EQ", "det": 3, "ftr": 1 })),
+    );
+
+    let totals = json_request(
+        address,
+        "PUT",
+        &format!("{}/gsc", project),
+        Some(json!({ "performance": 5, "online_data_input": 5 })),
+    );
+
+    assert_eq!(totals["unadjusted"], 17.0);
+    assert_eq!(totals["total_influence"], 10);
+
+    let summary = json_request(address, "GET", &format!("{}/summary", project), None);
+
+    assert_eq!(summary["external_input"], json!([0, 1, 0]));
+    assert_eq!(summary["internal_logical_file"], json!([0, 1, 0]));
+
+    let (status, _) = request(
+        address,
+        "DELETE",
+        &format!("{}/components/1", project),
+        None,
+    );
+
+    assert_eq!(status, 204);
+
+    let totals = json_request(address, "GET", &format!("{}/totals", project), None);
+
+    assert_eq!(totals["unadjusted"], 13.0);
+
+    let (status, report) = request(address, "GET", &format!("{}/report", project), None);
+
+    assert_eq!(status, 200);
+    assert!(report.contains("Project: Billing"));
+    assert!(report.contains("List customers"));
+
+    let projects = json_request(address, "GET", "/projects", None);
+
+    assert_eq!(projects[0]["name"], "Billing");
+}
+
+#[test]
+fn should_count_the_output_side_of_external_outputs() {
+    let address = spawn(CountingService::new());
+    let created = json_request(address, "POST", "/projects", None);
+    let project = format!("/projects/{}", created["id"]);
+
+    json_request(
+        address,
+        "POST",
+        &format!("{}/components", project),
+        Some(json!({ "name": "Invoice", "kind": "EO", "det_out": 6, "ftr_out": 3 })),
+    );
+
+    let summary = json_request(address, "GET", &format!("{}/summary", project), None);
+
+    assert_eq!(summary["external_output"], json!([0, 1, 0]));
+
+    let (status, _) = request(
+        address,
+        "POST",
+        &format!("{}/components", project),
+        Some(json!({ "name": "Customer", "kind": "ILF", "ftr_out": 1 })),
+    );
+
+    assert_eq!(status, 400);
+}
+
+#[test]
+fn should_reject_invalid_requests() {
+    let address = spawn(CountingService::new());
+    let created = json_request(address, "POST", "/projects", None);
+    let components = format!("/projects/{}/components", created["id"]);
+
+    let (status, body) = request(
+        address,
+        "POST",
+        &components,
+        Some(json!({ "name": "Customer", "kind": "ILF", "ftr": 2 })),
+    );
+
+    assert_eq!(status, 400);
+    assert_eq!(
+        serde_json::from_str::<Value>(&body).unwrap()["error"],
+        "`ftr` applies to transactions"
+    );
+
+    let (status, _) = request(
+        address,
+        "PUT",
+        &format!("/projects/{}/gsc", created["id"]),
+        Some(json!({ "performance": 6 })),
+    );
+
+    assert_eq!(status, 400);
+    assert_eq!(request(address, "GET", "/projects/99", None).0, 404);
+    assert_eq!(
+        request(address, "PUT", &format!("{}/7", components), None).0,
+        404
+    );
+    assert_eq!(request(address, "PATCH", "/projects", None).0, 405);
+}
+
+#[test]
+fn should_keep_state_in_a_file() {
+    let path = state_file("state");
+    let address = spawn(CountingService::open(&path).unwrap());
+    let created = json_request(
+        address,
+        "POST",
+        "/projects",
+        Some(json!({ "name": "Payroll" })),
+    );
+
+    json_request(
+        address,
+        "POST",
+        &format!("/projects/{}/components", created["id"]),
+        Some(json!({ "name": "Employee", "kind": "ILF", "det": 10, "ret": 1 })),
+    );
+
+    let reopened = spawn(CountingService::open(&path).unwrap());
+    let project = json_request(
+        reopened,
+        "GET",
+        &format!("/projects/{}", created["id"]),
+        None,
+    );
+
+    assert_eq!(project["metadata"]["name"], "Payroll");
+    assert_eq!(
+        project["basic_functional_components"][0]["name"],
+        "Employee"
+    );
+
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn should_undo_changes_that_can_not_be_saved() {
+    let path = env::temp_dir()
+        .join(format!("fpa-server-missing-{}", process::id()))
+        .join("state.json");
+    let address = spawn(CountingService::open(&path).unwrap());
+
+    assert_eq!(request(address, "POST", "/projects", None).0, 500);
+    assert_eq!(json_request(address, "GET", "/projects", None), json!([]));
+}
+
+#[test]
+fn should_refuse_oversized_bodies() {
+    let address = spawn(CountingService::new());
+
+    assert_eq!(
+        raw_request(
+            address,
+            "POST /projects HTTP/1.1\r\nContent-Length: 99999999999999\r\n\r\n"
+        ),
+        413
+    );
+    assert_eq!(request(address, "GET", "/projects", None).0, 200);
+}
+
+#[test]
+fn should_not_wait_forever_on_slow_clients() {
+    let mut service = CountingService::new();
+
+    service.set_read_timeout(Duration::from_millis(100));
+
+    let address = spawn(service);
+    let _silent = TcpStream::connect(address).unwrap();
+    let mut short = TcpStream::connect(address).unwrap();
+
+    write!(
+        short,
+        "POST /projects HTTP/1.1\r\nContent-Length: 100\r\n\r\n{{}}"
+    )
+    .unwrap();
+
+    assert_eq!(request(address, "GET", "/projects", None).0, 200);
+}
+
+#[test]
+fn should_limit_the_time_to_send_a_whole_request() {
+    let mut service = CountingService::new();
+
+    service.set_read_timeout(Duration::from_millis(300));
+
+    let address = spawn(service);
+    let mut trickle = TcpStream::connect(address).unwrap();
+    let mut reader = trickle.try_clone().unwrap();
+    let started = Instant::now();
+    let closed = thread::spawn(move || {
+        let _ = reader.read_to_end(&mut Vec::new());
+
+        started.elapsed()
+    });
+
+    for byte in "GET /projects HTTP/1.1\r\nX-Padding: "
+        .bytes()
+        .cycle()
+        .take(60)
+    {
+        if trickle.write_all(&[byte]).is_err() {
+            break;
+        }
+
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    assert!(closed.join().unwrap() < Duration::from_secs(2));
+}
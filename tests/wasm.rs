@@ -0,0 +1,43 @@
+// Run with `wasm-pack test --node -- --features wasm`.
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use fpa_rs::WasmProject;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn should_count_in_javascript() {
+    let mut project = WasmProject::new(Some("Billing".to_string()));
+
+    project.set_weights("EQ", 3, 4, 6).unwrap();
+    project
+        .add_component("EQ", "List customers", 3, 1, None, None)
+        .unwrap();
+
+    assert_eq!(project.component_complexity(0).unwrap(), "Simple");
+    assert_eq!(project.unadjusted(), 3f32);
+}
+
+#[wasm_bindgen_test]
+fn should_throw_on_invalid_input() {
+    let mut project = WasmProject::new(None);
+
+    assert!(project
+        .add_component("XX", "Unknown", 1, 1, None, None)
+        .is_err());
+    assert!(project.set_gsc("performance", 6).is_err());
+    assert!(project.remove_component(0).is_err());
+}
+
+#[wasm_bindgen_test]
+fn should_round_trip_json() {
+    let mut project = WasmProject::new(Some("Billing".to_string()));
+
+    project
+        .add_component("ILF", "Customer", 20, 2, None, None)
+        .unwrap();
+
+    let copy = WasmProject::from_json(&project.to_json().unwrap()).unwrap();
+
+    assert_eq!(copy.len(), 1);
+    assert_eq!(copy.to_json().unwrap(), project.to_json().unwrap());
+}